@@ -9,9 +9,13 @@ use embassy_stm32::time::Hertz;
 use embassy_sync::{blocking_mutex::raw::CriticalSectionRawMutex, mutex::Mutex};
 use embassy_embedded_hal::shared_bus::asynch::spi::SpiDevice;
 use embassy_time;
+use embedded_graphics::mono_font::{ascii::FONT_10X20, MonoTextStyle};
 use embedded_graphics::pixelcolor::Rgb565;
+use embedded_graphics::prelude::*;
+use embedded_graphics::primitives::{PrimitiveStyle, PrimitiveStyleBuilder, Rectangle};
+use embedded_graphics::text::Text;
 
-use gc9307_async::{Config as DisplayConfig, GC9307C, Orientation};
+use gc9307_async::{GC9307C, Orientation};
 #[cfg(feature = "software-rotation")]
 use gc9307_async::Rotation;
 use static_cell::StaticCell;
@@ -90,25 +94,13 @@ async fn main(_spawner: Spawner) {
     let spi = SpiDevice::new(spi_bus, cs);
 
     // Configure display with correct dimensions
-    let display_config = DisplayConfig {
-        rgb: false,
-        inverted: false,
-        orientation: Orientation::Landscape,
-        height: 172,  // Physical height in landscape mode
-        width: 320,   // Physical width in landscape mode
-        dx: 0,        // No X offset
-        dy: 34,       // Y offset as per successful examples
-    };
-
-    // Initialize display with new simplified constructor
     let buffer = unsafe { &mut *core::ptr::addr_of_mut!(DISPLAY_BUFFER) };
-    let mut display = GC9307C::<_, _, _, EmbassyTimer>::new(
-        display_config,
-        spi,
-        dc,
-        rst,
-        buffer,
-    );
+    let mut display = GC9307C::<_, _, _, EmbassyTimer>::builder()
+        .rgb(false)
+        .orientation(Orientation::Landscape)
+        .size(320, 172) // Physical size in landscape mode
+        .offset(0, 34) // Y offset as per successful examples
+        .build(spi, dc, rst, buffer);
 
     info!("Initializing display...");
     if let Err(_e) = display.init().await {
@@ -137,14 +129,55 @@ async fn main(_spawner: Spawner) {
                 info!("Test 4: Direction Markers");
                 test_direction_markers(&mut display).await;
             }
+            4 => {
+                info!("Test 5: embedded-graphics");
+                test_embedded_graphics(&mut display).await;
+            }
             _ => test_index = 0,
         }
 
-        test_index = (test_index + 1) % 4;
+        test_index = (test_index + 1) % 5;
         embassy_time::Timer::after_secs(2).await; // Faster cycling
     }
 }
 
+/// Test 5: embedded-graphics shapes and text through the DrawTarget impl
+async fn test_embedded_graphics<SPI, DC, RST>(display: &mut GC9307C<'_, SPI, DC, RST, EmbassyTimer>)
+where
+    SPI: embedded_hal_async::spi::SpiDevice,
+    DC: embedded_hal::digital::OutputPin<Error = core::convert::Infallible>,
+    RST: embedded_hal::digital::OutputPin<Error = core::convert::Infallible>,
+{
+    info!("Drawing embedded-graphics primitives...");
+
+    let _ = display.clear(BLACK);
+
+    let panel_style = PrimitiveStyleBuilder::new()
+        .stroke_color(WHITE)
+        .stroke_width(2)
+        .fill_color(BLUE)
+        .build();
+    if Rectangle::new(Point::new(20, 20), Size::new(280, 132))
+        .into_styled(panel_style)
+        .draw(display)
+        .is_err()
+    {
+        error!("Failed to draw rectangle");
+    }
+
+    let _ = Rectangle::new(Point::new(40, 100), Size::new(240, 8))
+        .into_styled(PrimitiveStyle::with_fill(YELLOW))
+        .draw(display);
+
+    let text_style = MonoTextStyle::new(&FONT_10X20, WHITE);
+    if Text::new("Hello GC9307!", Point::new(40, 60), text_style)
+        .draw(display)
+        .is_err()
+    {
+        error!("Failed to draw text");
+    }
+}
+
 /// Test 2: Vertical color stripes (from direct-spi example)
 async fn test_color_stripes<SPI, DC, RST>(display: &mut GC9307C<'_, SPI, DC, RST, EmbassyTimer>)
 where
@@ -0,0 +1,92 @@
+//! Blocking (non-async) usage of the driver
+//!
+//! Builds the driver in sync mode and fills the screen through a mock SPI device
+//! that discards all data, so the blocking API can be exercised on the host:
+//!
+//! ```sh
+//! cargo run --example sync_blocking --no-default-features
+//! ```
+//!
+//! On hardware, pass the HAL's blocking `embedded_hal::spi::SpiDevice` and output
+//! pins instead of the mocks, and implement `Timer` with a blocking delay.
+
+#[cfg(not(feature = "async"))]
+mod blocking {
+    use core::convert::Infallible;
+    use embedded_graphics_core::pixelcolor::{Rgb565, RgbColor};
+    use embedded_hal::digital::{ErrorType as PinErrorType, OutputPin};
+    use embedded_hal::spi::{ErrorType, Operation, SpiDevice};
+    use gc9307_async::{GC9307C, Orientation, Timer};
+
+    /// SPI device that accepts every transaction and discards the data
+    pub struct NullSpi;
+
+    impl ErrorType for NullSpi {
+        type Error = Infallible;
+    }
+
+    impl SpiDevice for NullSpi {
+        fn transaction(&mut self, operations: &mut [Operation<'_, u8>]) -> Result<(), Infallible> {
+            for operation in operations {
+                match operation {
+                    Operation::Read(data) | Operation::Transfer(data, _) => data.fill(0),
+                    _ => {}
+                }
+            }
+            Ok(())
+        }
+    }
+
+    /// Output pin that ignores every level change
+    pub struct DummyPin;
+
+    impl PinErrorType for DummyPin {
+        type Error = Infallible;
+    }
+
+    impl OutputPin for DummyPin {
+        fn set_low(&mut self) -> Result<(), Infallible> {
+            Ok(())
+        }
+
+        fn set_high(&mut self) -> Result<(), Infallible> {
+            Ok(())
+        }
+    }
+
+    /// Blocking delay; the sync form of `Timer::delay_ms` is a plain `fn`
+    pub struct StdTimer;
+
+    impl Timer for StdTimer {
+        fn delay_ms(milliseconds: u64) {
+            std::thread::sleep(std::time::Duration::from_millis(milliseconds));
+        }
+    }
+
+    pub fn run() {
+        let mut buffer = [0u8; gc9307_async::BUF_SIZE];
+        let mut display = GC9307C::<_, _, _, StdTimer>::builder()
+            .orientation(Orientation::Landscape)
+            .size(320, 172)
+            .offset(0, 34)
+            .build(NullSpi, DummyPin, DummyPin, &mut buffer);
+
+        display.init().expect("init failed");
+        display.clear(Rgb565::BLUE).expect("clear failed");
+        display
+            .fill_rect(10, 10, 50, 30, Rgb565::RED)
+            .expect("fill_rect failed");
+
+        println!("blocking path OK");
+    }
+}
+
+#[cfg(not(feature = "async"))]
+fn main() {
+    blocking::run();
+}
+
+#[cfg(feature = "async")]
+fn main() {
+    println!("This example shows the blocking API; run it with --no-default-features");
+}
@@ -0,0 +1,186 @@
+//! In-RAM RGB565 framebuffer
+//!
+//! [`FrameBuffer`] borrows a `&mut [u16]` of `width * height` pixels and draws into
+//! it without touching the bus: through [`set_pixel`](FrameBuffer::set_pixel),
+//! [`fill_rect`](FrameBuffer::fill_rect) or embedded-graphics' `DrawTarget`. A
+//! finished frame goes out in one window write with
+//! [`GC9307C::present`](crate::GC9307C::present), so partially drawn frames are
+//! never visible. A full 172x320 frame takes about 110 KB.
+//!
+//! Every draw grows a bounding dirty rectangle, and `present` sends only that
+//! rectangle: a clock updating a few digits moves a few hundred bytes per second
+//! instead of the whole frame.
+
+use core::convert::Infallible;
+
+use embedded_graphics_core::Pixel;
+use embedded_graphics_core::draw_target::DrawTarget;
+use embedded_graphics_core::geometry::{OriginDimensions, Point, Size};
+use embedded_graphics_core::pixelcolor::Rgb565;
+use embedded_graphics_core::pixelcolor::raw::RawU16;
+use embedded_graphics_core::prelude::RawData;
+use embedded_graphics_core::primitives::Rectangle;
+
+/// Row-major RGB565 framebuffer over a borrowed pixel slice
+#[derive(Debug)]
+pub struct FrameBuffer<'a> {
+    pixels: &'a mut [u16],
+    width: u16,
+    height: u16,
+    /// Inclusive (x0, y0, x1, y1) bounds of everything drawn since the last present
+    dirty: Option<(u16, u16, u16, u16)>,
+}
+
+impl<'a> FrameBuffer<'a> {
+    /// Wrap `pixels` as a `width`x`height` frame, or `None` if it holds fewer than
+    /// `width * height` pixels
+    ///
+    /// The whole frame starts dirty, so the first present sends all of it.
+    pub fn new(pixels: &'a mut [u16], width: u16, height: u16) -> Option<Self> {
+        let len = width as usize * height as usize;
+        let pixels = pixels.get_mut(..len)?;
+        let mut fb = Self {
+            pixels,
+            width,
+            height,
+            dirty: None,
+        };
+        fb.mark_all_dirty();
+        Some(fb)
+    }
+
+    /// Bounding `(x, y, width, height)` of everything drawn since the last
+    /// [`mark_clean`](Self::mark_clean), or `None` if nothing was
+    pub fn dirty_region(&self) -> Option<(u16, u16, u16, u16)> {
+        self.dirty
+            .map(|(x0, y0, x1, y1)| (x0, y0, x1 - x0 + 1, y1 - y0 + 1))
+    }
+
+    /// Forget the dirty region, e.g. after the frame was sent;
+    /// [`GC9307C::present`](crate::GC9307C::present) does this itself
+    pub fn mark_clean(&mut self) {
+        self.dirty = None;
+    }
+
+    /// Mark the whole frame dirty, e.g. after the screen was drawn over directly
+    pub fn mark_all_dirty(&mut self) {
+        self.dirty = None;
+        if self.width > 0 && self.height > 0 {
+            self.mark_dirty(0, 0, self.width - 1, self.height - 1);
+        }
+    }
+
+    fn mark_dirty(&mut self, x0: u16, y0: u16, x1: u16, y1: u16) {
+        self.dirty = Some(match self.dirty {
+            Some((dx0, dy0, dx1, dy1)) => (dx0.min(x0), dy0.min(y0), dx1.max(x1), dy1.max(y1)),
+            None => (x0, y0, x1, y1),
+        });
+    }
+
+    /// Frame width in pixels
+    pub fn width(&self) -> u16 {
+        self.width
+    }
+
+    /// Frame height in pixels
+    pub fn height(&self) -> u16 {
+        self.height
+    }
+
+    /// Raw RGB565 pixels, row-major, as [`GC9307C::flush_region`](crate::GC9307C::flush_region) takes them
+    pub fn pixels(&self) -> &[u16] {
+        self.pixels
+    }
+
+    /// Color at (x, y), or `None` outside the frame
+    pub fn pixel(&self, x: u16, y: u16) -> Option<Rgb565> {
+        (x < self.width && y < self.height)
+            .then(|| Rgb565::from(RawU16::new(self.pixels[self.index(x, y)])))
+    }
+
+    /// Set one pixel; coordinates outside the frame are ignored
+    pub fn set_pixel(&mut self, x: u16, y: u16, color: Rgb565) {
+        if x < self.width && y < self.height {
+            let index = self.index(x, y);
+            self.pixels[index] = raw(color);
+            self.mark_dirty(x, y, x, y);
+        }
+    }
+
+    /// Fill a rectangle, clipped to the frame
+    pub fn fill_rect(&mut self, x: u16, y: u16, width: u16, height: u16, color: Rgb565) {
+        if x >= self.width || y >= self.height {
+            return; // Outside the frame
+        }
+
+        let width = width.min(self.width - x);
+        let height = height.min(self.height - y);
+        if width == 0 || height == 0 {
+            return; // Nothing to draw
+        }
+
+        let color = raw(color);
+        for row in y..y + height {
+            let start = self.index(x, row);
+            self.pixels[start..start + width as usize].fill(color);
+        }
+        self.mark_dirty(x, y, x + width - 1, y + height - 1);
+    }
+
+    /// Fill the whole frame
+    pub fn clear(&mut self, color: Rgb565) {
+        self.pixels.fill(raw(color));
+        self.mark_all_dirty();
+    }
+
+    fn index(&self, x: u16, y: u16) -> usize {
+        y as usize * self.width as usize + x as usize
+    }
+}
+
+fn raw(color: Rgb565) -> u16 {
+    RawU16::from(color).into_inner()
+}
+
+impl OriginDimensions for FrameBuffer<'_> {
+    fn size(&self) -> Size {
+        Size::new(self.width as u32, self.height as u32)
+    }
+}
+
+impl DrawTarget for FrameBuffer<'_> {
+    type Color = Rgb565;
+    type Error = Infallible;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        for Pixel(point, color) in pixels {
+            if let (Ok(x), Ok(y)) = (u16::try_from(point.x), u16::try_from(point.y)) {
+                self.set_pixel(x, y, color);
+            }
+        }
+        Ok(())
+    }
+
+    fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+        let frame = Rectangle::new(Point::zero(), self.size());
+        let area = area.intersection(&frame);
+        if area.bottom_right().is_some() {
+            self.fill_rect(
+                area.top_left.x as u16,
+                area.top_left.y as u16,
+                area.size.width as u16,
+                area.size.height as u16,
+                color,
+            );
+        }
+        Ok(())
+    }
+
+    fn clear(&mut self, color: Self::Color) -> Result<(), Self::Error> {
+        FrameBuffer::clear(self, color);
+        Ok(())
+    }
+}
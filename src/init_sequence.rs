@@ -0,0 +1,120 @@
+//! Runtime-assembled initialization sequences
+//!
+//! [`InitSequence`] owns its command bytes and parameters in fixed-capacity
+//! `heapless` vectors, so a per-panel sequence can be built at runtime (e.g. from
+//! calibration data in EEPROM) without `'static` storage or `alloc`.
+
+use crate::InitCommand;
+
+/// The sequence ran out of command or parameter capacity
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct CapacityError;
+
+#[derive(Debug, Clone, Copy)]
+struct Entry {
+    cmd: u8,
+    start: usize,
+    len: usize,
+    delay_ms: u32,
+}
+
+/// Initialization sequence of up to `N` commands with `P` parameter bytes in total
+///
+/// Pass `&sequence` to [`GC9307C::init_with`](crate::GC9307C::init_with).
+#[derive(Debug, Clone, Default)]
+pub struct InitSequence<const N: usize, const P: usize> {
+    commands: heapless::Vec<Entry, N>,
+    params: heapless::Vec<u8, P>,
+}
+
+impl<const N: usize, const P: usize> InitSequence<N, P> {
+    /// Create an empty sequence
+    pub const fn new() -> Self {
+        Self {
+            commands: heapless::Vec::new(),
+            params: heapless::Vec::new(),
+        }
+    }
+
+    /// Append a command without a trailing delay
+    pub fn push(&mut self, cmd: u8, params: &[u8]) -> Result<(), CapacityError> {
+        self.push_with_delay(cmd, params, 0)
+    }
+
+    /// Append a command followed by a delay
+    pub fn push_with_delay(
+        &mut self,
+        cmd: u8,
+        params: &[u8],
+        delay_ms: u32,
+    ) -> Result<(), CapacityError> {
+        if self.commands.is_full() {
+            return Err(CapacityError);
+        }
+
+        let start = self.params.len();
+        self.params
+            .extend_from_slice(params)
+            .map_err(|_| CapacityError)?;
+        self.commands
+            .push(Entry {
+                cmd,
+                start,
+                len: params.len(),
+                delay_ms,
+            })
+            .map_err(|_| CapacityError)
+    }
+
+    /// Append every command of a borrowed sequence, e.g. [`DEFAULT_INIT_SEQUENCE`](crate::DEFAULT_INIT_SEQUENCE)
+    pub fn extend_from_slice(&mut self, commands: &[InitCommand<'_>]) -> Result<(), CapacityError> {
+        for command in commands {
+            self.push_with_delay(command.cmd, command.params, command.delay_ms)?;
+        }
+        Ok(())
+    }
+
+    /// Number of commands
+    pub fn len(&self) -> usize {
+        self.commands.len()
+    }
+
+    /// Whether the sequence has no commands
+    pub fn is_empty(&self) -> bool {
+        self.commands.is_empty()
+    }
+
+    /// Iterate over the commands, borrowing their parameters from the sequence
+    pub fn iter(&self) -> Iter<'_> {
+        Iter {
+            entries: self.commands.iter(),
+            params: &self.params,
+        }
+    }
+}
+
+impl<'s, const N: usize, const P: usize> IntoIterator for &'s InitSequence<N, P> {
+    type Item = InitCommand<'s>;
+    type IntoIter = Iter<'s>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterator over the commands of an [`InitSequence`]
+pub struct Iter<'s> {
+    entries: core::slice::Iter<'s, Entry>,
+    params: &'s [u8],
+}
+
+impl<'s> Iterator for Iter<'s> {
+    type Item = InitCommand<'s>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let entry = self.entries.next()?;
+        let params = &self.params[entry.start..entry.start + entry.len];
+        Some(InitCommand::with_delay(entry.cmd, params, entry.delay_ms))
+    }
+}
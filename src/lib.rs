@@ -1,28 +1,72 @@
 #![no_std]
 
+use core::borrow::Borrow;
 use core::convert::Infallible;
 
-use embedded_graphics_core::pixelcolor::{Rgb565, raw::RawU16};
+use embedded_graphics_core::Pixel;
+use embedded_graphics_core::draw_target::DrawTarget;
+use embedded_graphics_core::geometry::{OriginDimensions, Point, Size};
+use embedded_graphics_core::pixelcolor::{Rgb565, RgbColor, raw::RawU16};
 use embedded_graphics_core::prelude::RawData;
+use embedded_graphics_core::primitives::{PointsIter, Rectangle};
+#[cfg(not(feature = "async"))]
+use embedded_hal::delay::DelayNs;
 use embedded_hal::digital::OutputPin;
+#[cfg(feature = "async")]
+use embedded_hal_async::delay::DelayNs;
+#[cfg(all(feature = "embedded-io", not(feature = "async")))]
+use embedded_io::Read as IoRead;
+#[cfg(all(feature = "embedded-io", feature = "async"))]
+use embedded_io_async::Read as IoRead;
+
+use embedded_hal::spi::Operation;
 #[cfg(not(feature = "async"))]
 use embedded_hal::spi::SpiDevice;
 #[cfg(feature = "async")]
 use embedded_hal_async::spi::SpiDevice;
+use font::GlyphSource;
+
+pub mod font;
+#[cfg(feature = "framebuffer")]
+pub mod framebuffer;
+#[cfg(feature = "heapless")]
+pub mod init_sequence;
+pub mod nine_bit;
+#[cfg(test)]
+mod tests;
+
+// Native (portrait) geometry of the 1.47" module, derived from `Panel::Hsd147`
+/// Physical width (short edge)
+pub const SCREEN_WIDTH: u16 = Panel::Hsd147.geometry().0;
+/// Physical height (long edge)
+pub const SCREEN_HEIGHT: u16 = Panel::Hsd147.geometry().1;
+/// GRAM column offset of the short edge in portrait; it becomes `Config::dy` in landscape
+pub const OFFSET_X: u16 = Panel::Hsd147.geometry().2;
+/// GRAM row offset in portrait (none); it becomes `Config::dx` in landscape
+pub const OFFSET_Y: u16 = Panel::Hsd147.geometry().3;
+
+/// Working buffer size in bytes needed to hold `max_run_pixels` RGB565 pixels
+pub const fn required_buffer_size(max_run_pixels: usize) -> usize {
+    max_run_pixels * 2
+}
 
-// Screen dimensions for GC9307 172RGB×320
-pub const SCREEN_WIDTH: u16 = 172; // Physical width (short edge)
-pub const SCREEN_HEIGHT: u16 = 320; // Physical height (long edge)
-// Display offset (applied to coordinates)
-pub const OFFSET_X: u16 = 34; // Offset on X axis (short edge)
-pub const OFFSET_Y: u16 = 0; // No offset on Y axis
-
-// Buffer size for chunked operations
-pub const BUF_SIZE: usize = 24 * 48 * 2;
-const MAX_DATA_LEN: usize = BUF_SIZE / 2;
-
+// Buffer size for chunked operations (a 24x48 glyph)
+pub const BUF_SIZE: usize = required_buffer_size(24 * 48);
+
+/// GC9307 command bytes, for [`GC9307C::command`] and custom init sequences
+///
+/// Power registers have no typed wrappers on purpose. VCOM has no register of its own
+/// here: it is set through VREG1A/VREG1B ([`GC9307C::set_vreg`]), and the vendor
+/// sequence notes that it is burned into OTP ("vcom 烧录了"), so `init` leaves it
+/// alone. Power Control 1 (C1h) is not written by the vendor sequence and its
+/// parameter layout is not documented for this module, so there is nothing to
+/// validate a wrapper against; send it with [`GC9307C::command`] if a panel needs it.
 #[derive(Debug, Clone, Copy)]
 pub enum Instruction {
+    /// No Operation (00h) - Ends a pending Memory Write without other effect
+    Nop = 0x00,
+    /// Software Reset (01h) - Restores registers to their defaults
+    SoftwareReset = 0x01,
     /// Read Display Identification (04h) - Returns manufacturer and version information
     ReadDisplayId = 0x04,
     /// Read Display Status (09h) - Checks display operating state
@@ -52,17 +96,39 @@ pub enum Instruction {
     PageAddressSet = 0x2B,
     /// Memory Write (2Ch) - Write to memory
     MemoryWrite = 0x2C,
+    /// Memory Read (2Eh) - Read from memory
+    MemoryRead = 0x2E,
 
+    /// Partial Area (30h) - Start/end rows of the partial display area
+    PartialArea = 0x30,
+    /// Vertical Scrolling Definition (33h) - Fixed/scroll area heights
+    VerticalScrollingDefinition = 0x33,
+
+    /// Tearing Effect Line Off (34h) - Disable the TE output
+    TearingEffectDisable = 0x34,
     /// Tearing Effect Line On (35h) - Enable VSync output
     TearingEffectEnable = 0x35,
     /// Memory Access Control (36h) - GRAM orientation/order
     MemoryAccessControl = 0x36,
+    /// Vertical Scroll Start Address (37h) - First line of the scroll area
+    VerticalScrollStartAddress = 0x37,
+    /// Idle Mode Off (38h) - Full color depth
+    IdleModeOff = 0x38,
+    /// Idle Mode On (39h) - Reduced 8-color depth for low power
+    IdleModeOn = 0x39,
     /// Pixel Format Set (3Ah) - Color depth configuration
     PixelFormatSet = 0x3A,
+    /// Memory Read Continue (3Eh) - Continue reading from the last position
+    MemoryReadContinue = 0x3E,
 
     /// Tearing Effect Control (44h) - VSync line address
     TearingEffectControl = 0x44,
 
+    /// Write Display Brightness (51h) - DISBV backlight level
+    WriteDisplayBrightness = 0x51,
+    /// Write CTRL Display (53h) - Brightness control block enable
+    WriteCtrlDisplay = 0x53,
+
     /// VCore Voltage Regulation (A7h) - Core voltage adjustment
     VcoreVoltageControl = 0xA7,
 
@@ -73,7 +139,7 @@ pub enum Instruction {
     /// Display Function Control (B6h) - Scan direction/number
     DisplayFunctionControl = 0xB6,
 
-    /// Power Control 1 (C1h) - Main voltage regulation
+    /// Power Control 1 (C1h) - Main voltage regulation; raw access only, see above
     PowerControl1 = 0xC1,
     /// VREG1A Control (C3h) - Positive charge pump
     Vreg1aControl = 0xC3,
@@ -105,6 +171,36 @@ pub enum Instruction {
     ExtendedRegAccess1 = 0xFE,
 }
 
+impl Instruction {
+    /// Number of parameter bytes the command takes, if it is fixed
+    ///
+    /// `None` for read commands, Memory Write (streamed pixel data) and registers whose
+    /// length varies or is not documented.
+    pub const fn param_len(self) -> Option<usize> {
+        use Instruction::*;
+        match self {
+            Nop | SoftwareReset | SleepIn | SleepOut | PartialModeOn | NormalDisplayOn
+            | DisplayInversionOff | DisplayInversionOn | DisplayOff | DisplayOn | IdleModeOff
+            | IdleModeOn | TearingEffectDisable | ExtendedRegAccess1 | ExtendedRegAccess2 => {
+                Some(0)
+            }
+            TearingEffectEnable
+            | MemoryAccessControl
+            | PixelFormatSet
+            | WriteDisplayBrightness
+            | WriteCtrlDisplay
+            | Vreg1aControl
+            | Vreg1bControl
+            | Vreg2aControl
+            | SpiInterfaceControl => Some(1),
+            VerticalScrollStartAddress | TearingEffectControl | FrameRateControl => Some(2),
+            ColumnAddressSet | PageAddressSet | PartialArea => Some(4),
+            VerticalScrollingDefinition | GammaSet1 | GammaSet2 | GammaSet3 | GammaSet4 => Some(6),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Clone, Copy)]
 pub enum Orientation {
     Portrait = 0x40,
@@ -113,8 +209,38 @@ pub enum Orientation {
     LandscapeSwapped = 0xE0,
 }
 
-#[cfg(feature = "software-rotation")]
-/// Software rotation angles
+/// MADCTL (36h) BGR color order bit
+const MADCTL_BGR: u8 = 0x08;
+/// MADCTL (36h) row/column exchange bit
+const MADCTL_MV: u8 = 0x20;
+
+impl Orientation {
+    /// Memory Access Control (36h) byte for this orientation and color order
+    ///
+    /// The orientation supplies the MY/MX/MV bits; the BGR bit is set when `rgb` is false.
+    ///
+    /// ```
+    /// use gc9307_async::Orientation;
+    ///
+    /// assert_eq!(Orientation::Portrait.madctl(true), 0x40);
+    /// assert_eq!(Orientation::Portrait.madctl(false), 0x48);
+    /// assert_eq!(Orientation::Landscape.madctl(true), 0x20);
+    /// assert_eq!(Orientation::Landscape.madctl(false), 0x28);
+    /// assert_eq!(Orientation::PortraitSwapped.madctl(true), 0x80);
+    /// assert_eq!(Orientation::PortraitSwapped.madctl(false), 0x88);
+    /// assert_eq!(Orientation::LandscapeSwapped.madctl(true), 0xE0);
+    /// assert_eq!(Orientation::LandscapeSwapped.madctl(false), 0xE8);
+    /// ```
+    pub const fn madctl(self, rgb: bool) -> u8 {
+        if rgb {
+            self as u8
+        } else {
+            self as u8 | MADCTL_BGR
+        }
+    }
+}
+
+/// Rotation angles (software rotation or hardware MADCTL rotation)
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Rotation {
     Deg0,
@@ -123,7 +249,6 @@ pub enum Rotation {
     Deg270,
 }
 
-#[cfg(feature = "software-rotation")]
 impl Rotation {
     /// Get the next rotation in the cycle
     pub fn next(self) -> Self {
@@ -144,6 +269,15 @@ impl Rotation {
             Rotation::Deg270 => 270,
         }
     }
+    /// MADCTL orientation preset rotating the panel by this angle in hardware
+    pub fn orientation(self) -> Orientation {
+        match self {
+            Rotation::Deg0 => Orientation::Portrait,
+            Rotation::Deg90 => Orientation::Landscape,
+            Rotation::Deg180 => Orientation::PortraitSwapped,
+            Rotation::Deg270 => Orientation::LandscapeSwapped,
+        }
+    }
 }
 
 #[derive(Clone, Copy)]
@@ -155,43 +289,606 @@ pub struct Config {
     pub width: u16,
     pub dx: u16,
     pub dy: u16,
+    /// Pixels per SPI write for solid fills, clamped to the working buffer size
+    pub batch_pixels: usize,
+    /// Preferred largest single SPI write in bytes, bounded by the working buffer
+    ///
+    /// When set, solid fills use it instead of `batch_pixels` and streamed writes never
+    /// exceed it. Useful with HALs where every `write` has a fixed overhead (set it
+    /// high) or a DMA length limit (set it to the limit).
+    pub max_transfer_bytes: Option<usize>,
+    /// Wait after a hardware or software reset before the first command, in milliseconds
+    pub reset_delay_ms: u32,
+    /// Wait after Sleep Out (11h) during init, in milliseconds
+    ///
+    /// Replaces the delay of any Sleep Out command in the init sequence.
+    pub sleepout_delay_ms: u32,
+    /// Yield to the executor after every this many pixel-data SPI writes
+    ///
+    /// A full-screen fill is hundreds of back-to-back writes; with a fast SPI future
+    /// that completes without suspending, other tasks don't run until it ends. Set
+    /// this to let them in between batches (`Some(0)` acts like `Some(1)`). No effect
+    /// in blocking builds.
+    pub yield_every_batches: Option<u32>,
 }
 
 impl Default for Config {
+    /// The 1.47" module ([`Panel::Hsd147`]) in landscape: 320×172, `dx: 0, dy: 34`
     fn default() -> Self {
-        Self {
+        let mut config = Self {
             rgb: false,
             inverted: false,
             orientation: Orientation::Landscape,
-            height: 172,
-            width: 320,
+            height: 0,
+            width: 0,
+            dx: 0,
+            dy: 0,
+            batch_pixels: 512,
+            max_transfer_bytes: None,
+            reset_delay_ms: 120,
+            sleepout_delay_ms: 200,
+            yield_every_batches: None,
+        };
+        config.apply_panel(Panel::Hsd147);
+        config
+    }
+}
+
+/// Tearing effect (TE) output mode, see [`GC9307C::set_tearing_effect`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TearingEffect {
+    /// TE output disabled (34h)
+    Off,
+    /// Pulse on vertical blanking only (35h, mode 0); the default after init
+    VBlank,
+    /// Pulse on both vertical and horizontal blanking (35h, mode 1)
+    VAndHBlank,
+}
+
+/// Built-in full-screen patterns for hardware bring-up, see [`GC9307C::draw_test_pattern`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TestPattern {
+    /// Eight vertical bars: white, yellow, cyan, green, magenta, red, blue, black
+    ///
+    /// Swapped red/blue bars mean the `rgb` flag is wrong.
+    ColorBars,
+    /// Black and white squares of `size` pixels, starting white at the origin
+    Checkerboard { size: u16 },
+    /// Red, green and blue ramps from dark (left) to full (right), one band each
+    Gradient,
+    /// 1px white border with red, green, blue and white corner squares (clockwise
+    /// from the top left), to check offset and orientation
+    CornerMarkers,
+}
+
+impl TestPattern {
+    /// Color of pixel (x, y) on a `width` × `height` screen
+    fn color_at(self, x: u32, y: u32, width: u32, height: u32) -> Rgb565 {
+        match self {
+            TestPattern::ColorBars => {
+                const BARS: [Rgb565; 8] = [
+                    Rgb565::WHITE,
+                    Rgb565::YELLOW,
+                    Rgb565::CYAN,
+                    Rgb565::GREEN,
+                    Rgb565::MAGENTA,
+                    Rgb565::RED,
+                    Rgb565::BLUE,
+                    Rgb565::BLACK,
+                ];
+                BARS[(x * 8 / width) as usize]
+            }
+            TestPattern::Checkerboard { size } => {
+                let size = size.max(1) as u32;
+                if (x / size + y / size).is_multiple_of(2) {
+                    Rgb565::WHITE
+                } else {
+                    Rgb565::BLACK
+                }
+            }
+            TestPattern::Gradient => {
+                let band = y * 3 / height;
+                let max = if band == 1 { 63 } else { 31 };
+                let level = (x * (max + 1) / width) as u8;
+                match band {
+                    0 => Rgb565::new(level, 0, 0),
+                    1 => Rgb565::new(0, level, 0),
+                    _ => Rgb565::new(0, 0, level),
+                }
+            }
+            TestPattern::CornerMarkers => {
+                const MARKER: u32 = 16;
+                let (left, top) = (x < MARKER, y < MARKER);
+                let (right, bottom) = (x + MARKER >= width, y + MARKER >= height);
+                match (left, right, top, bottom) {
+                    (true, _, true, _) => Rgb565::RED,
+                    (_, true, true, _) => Rgb565::GREEN,
+                    (_, true, _, true) => Rgb565::BLUE,
+                    (true, _, _, true) => Rgb565::WHITE,
+                    _ if x == 0 || y == 0 || x + 1 == width || y + 1 == height => Rgb565::WHITE,
+                    _ => Rgb565::BLACK,
+                }
+            }
+        }
+    }
+}
+
+/// GC9307 module geometry: native (portrait) size and GRAM offset
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Panel {
+    /// 1.47" HSD module: 172×320 with a 34-column GRAM offset
+    Hsd147,
+    /// Any other module, described in its native portrait orientation
+    Generic {
+        width: u16,
+        height: u16,
+        offset_x: u16,
+        offset_y: u16,
+    },
+}
+
+impl Panel {
+    /// Native (portrait) `(width, height, offset_x, offset_y)`
+    pub const fn geometry(self) -> (u16, u16, u16, u16) {
+        match self {
+            Panel::Hsd147 => (172, 320, 34, 0),
+            Panel::Generic {
+                width,
+                height,
+                offset_x,
+                offset_y,
+            } => (width, height, offset_x, offset_y),
+        }
+    }
+}
+
+impl Config {
+    /// Default configuration for `panel` in `orientation`
+    ///
+    /// Size and offset are swapped between axes for the landscape orientations.
+    pub fn for_panel(panel: Panel, orientation: Orientation) -> Self {
+        let mut config = Config {
+            orientation,
+            ..Default::default()
+        };
+        config.apply_panel(panel);
+        config
+    }
+
+    /// Same configuration with the GRAM offset cleared, for modules whose visible area
+    /// starts at GRAM (0, 0)
+    pub const fn no_offset(self) -> Self {
+        Self {
             dx: 0,
-            dy: 34,
+            dy: 0,
+            ..self
+        }
+    }
+
+    /// Set size and offset from `panel` for the configured orientation
+    ///
+    /// The panel's native offset is the single source of truth: `offset_x` lies on the
+    /// short edge, so it lands in `dx` in portrait and in `dy` once MV swaps the axes.
+    fn apply_panel(&mut self, panel: Panel) {
+        let (width, height, offset_x, offset_y) = panel.geometry();
+        if self.orientation as u8 & MADCTL_MV != 0 {
+            (self.width, self.height, self.dx, self.dy) = (height, width, offset_y, offset_x);
+        } else {
+            (self.width, self.height, self.dx, self.dy) = (width, height, offset_x, offset_y);
+        }
+    }
+}
+
+/// Largest width or height the controller can address
+const MAX_DIMENSION: u16 = 320;
+/// Column address space: the 320-line axis plus the panel's 34-column GRAM offset
+const MAX_COLUMN_END: u16 = 354;
+/// Row address space
+const MAX_ROW_END: u16 = 320;
+
+impl Config {
+    /// Check that the size and offsets fit the controller's GRAM
+    ///
+    /// Catches swapped `width`/`height` for the chosen orientation and offsets that
+    /// would push writes past the end of GRAM. [`GC9307C::init`] runs this first.
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        if self.width == 0 || self.height == 0 {
+            return Err(ConfigError::ZeroSize);
+        }
+        if self.width > MAX_DIMENSION {
+            return Err(ConfigError::WidthTooLarge(self.width));
+        }
+        if self.height > MAX_DIMENSION {
+            return Err(ConfigError::HeightTooLarge(self.height));
+        }
+        if self.dx as u32 + self.width as u32 > MAX_COLUMN_END as u32 {
+            return Err(ConfigError::ColumnsOutOfRange {
+                dx: self.dx,
+                width: self.width,
+            });
+        }
+        if self.dy as u32 + self.height as u32 > MAX_ROW_END as u32 {
+            return Err(ConfigError::RowsOutOfRange {
+                dy: self.dy,
+                height: self.height,
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Reason a [`Config`] was rejected by [`Config::validate`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ConfigError {
+    /// `width` or `height` is zero
+    ZeroSize,
+    /// `width` exceeds 320
+    WidthTooLarge(u16),
+    /// `height` exceeds 320
+    HeightTooLarge(u16),
+    /// `dx + width` exceeds 354
+    ColumnsOutOfRange { dx: u16, width: u16 },
+    /// `dy + height` exceeds 320
+    RowsOutOfRange { dy: u16, height: u16 },
+}
+
+impl core::fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ConfigError::ZeroSize => f.write_str("width or height is zero"),
+            ConfigError::WidthTooLarge(width) => write!(f, "width {width} exceeds 320"),
+            ConfigError::HeightTooLarge(height) => write!(f, "height {height} exceeds 320"),
+            ConfigError::ColumnsOutOfRange { dx, width } => {
+                write!(f, "dx {dx} + width {width} exceeds 354 columns")
+            }
+            ConfigError::RowsOutOfRange { dy, height } => {
+                write!(f, "dy {dy} + height {height} exceeds 320 rows")
+            }
+        }
+    }
+}
+
+/// A single command of an initialization sequence
+#[derive(Debug, Clone, Copy)]
+pub struct InitCommand<'a> {
+    /// Command byte
+    pub cmd: u8,
+    /// Parameter bytes sent in data mode
+    pub params: &'a [u8],
+    /// Delay after the command, in milliseconds
+    pub delay_ms: u32,
+}
+
+impl<'a> InitCommand<'a> {
+    /// Command without a trailing delay
+    pub const fn new(cmd: u8, params: &'a [u8]) -> Self {
+        Self {
+            cmd,
+            params,
+            delay_ms: 0,
+        }
+    }
+
+    /// Command followed by a delay
+    pub const fn with_delay(cmd: u8, params: &'a [u8], delay_ms: u32) -> Self {
+        Self {
+            cmd,
+            params,
+            delay_ms,
         }
     }
 }
 
+/// Gamma tables written by [`DEFAULT_INIT_SEQUENCE`], in F0h..F3h order
+///
+/// See [`GC9307C::set_gamma`] to override them per panel.
+pub const DEFAULT_GAMMA: [[u8; 6]; 4] = [
+    [0x06, 0x08, 0x08, 0x06, 0x05, 0x1d],
+    [0x3b, 0x68, 0x66, 0x36, 0x35, 0x2f],
+    [0x00, 0x01, 0x09, 0x07, 0x04, 0x23],
+    [0x37, 0x6a, 0x66, 0x37, 0x35, 0x35],
+];
+
+/// VREG1A (C3h) / VREG1B (C4h) values from the vendor reference sequence
+///
+/// [`DEFAULT_INIT_SEQUENCE`] does not write them: the HSD module's VCOM is programmed
+/// into OTP at the factory, and the vendor sequence leaves these commented out so the
+/// per-unit calibration stays in effect. Use them as a starting point for
+/// [`GC9307C::set_vreg`] on panels without OTP calibration.
+pub const DEFAULT_VREG: (u8, u8) = (0x29, 0x45);
+
+/// Complete initialization sequence from docs/1.47寸IPS初始化GC9307+HSD.txt
+pub const DEFAULT_INIT_SEQUENCE: &[InitCommand<'static>] = &[
+    // Enable extended register access
+    InitCommand::new(Instruction::ExtendedRegAccess1 as u8, &[]),
+    InitCommand::new(Instruction::ExtendedRegAccess2 as u8, &[]),
+    // Pixel format; MADCTL (36h) is written from the Config by init_with afterwards
+    InitCommand::new(Instruction::PixelFormatSet as u8, &[0x05]), // 16-bit color
+    // Power regulation settings (0x85-0x8F series)
+    InitCommand::new(0x85, &[0xc0]),
+    InitCommand::new(0x86, &[0x98]),
+    InitCommand::new(0x87, &[0x28]),
+    InitCommand::new(0x89, &[0x33]),
+    InitCommand::new(0x8B, &[0x84]),
+    InitCommand::new(0x8D, &[0x3B]),
+    InitCommand::new(0x8E, &[0x0f]),
+    InitCommand::new(0x8F, &[0x70]),
+    // Frame rate control
+    InitCommand::new(Instruction::FrameRateControl as u8, &[0x13, 0x17]),
+    // Additional power settings
+    InitCommand::new(0xec, &[0x57, 0x07, 0xff]),
+    InitCommand::new(0xed, &[0x18, 0x09]),
+    InitCommand::new(Instruction::Vreg2aControl as u8, &[0x10]),
+    // Extended register settings
+    InitCommand::new(0xff, &[0x61]),
+    InitCommand::new(0x99, &[0x3A]),
+    InitCommand::new(0x9d, &[0x43]),
+    InitCommand::new(0x98, &[0x3e]),
+    InitCommand::new(0x9c, &[0x4b]),
+    // Gamma correction settings (complete sequence)
+    InitCommand::new(Instruction::GammaSet1 as u8, &DEFAULT_GAMMA[0]),
+    InitCommand::new(Instruction::GammaSet3 as u8, &DEFAULT_GAMMA[2]),
+    InitCommand::new(Instruction::GammaSet2 as u8, &DEFAULT_GAMMA[1]),
+    InitCommand::new(Instruction::GammaSet4 as u8, &DEFAULT_GAMMA[3]),
+    // Additional display control registers
+    InitCommand::new(0xFA, &[0x80, 0x0f]),
+    InitCommand::new(0xBE, &[0x11]), // source bias
+    InitCommand::new(0xCB, &[0x02]),
+    InitCommand::new(0xCD, &[0x22]),
+    InitCommand::new(0x9B, &[0xFF]),
+    // Tearing effect
+    InitCommand::new(Instruction::TearingEffectEnable as u8, &[0x00]),
+    InitCommand::new(Instruction::TearingEffectControl as u8, &[0x00, 0x0a]),
+    // Sleep out (init waits Config::sleepout_delay_ms instead of 200ms) and display on
+    InitCommand::with_delay(Instruction::SleepOut as u8, &[], 200), // Sleep out
+    InitCommand::new(Instruction::DisplayOn as u8, &[]),            // Display on
+    InitCommand::new(Instruction::MemoryWrite as u8, &[]),          // Memory write
+];
+
+/// Driver error; `E` is the SPI device's error type, `PE` the pins'
+///
+/// With the `defmt` feature it implements `defmt::Format` when `E` and `PE` do, so
+/// `defmt::error!("{}", e)` works directly.
 #[derive(Debug)]
-pub enum Error<E = ()> {
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Error<E = (), PE = Infallible> {
     /// Communication error
     Comm(E),
     /// Pin setting error
-    Pin(Infallible),
+    Pin(PE),
+    /// Invalid argument (e.g. data length not matching the target area)
+    InvalidArgument,
+    /// The [`Config`] does not fit the controller, see [`Config::validate`]
+    Config(ConfigError),
+    /// Drawing before [`init`](GC9307C::init) succeeded, or after a reset
+    NotInitialized,
+    /// The image reader of [`write_image_from`](GC9307C::write_image_from) failed, or
+    /// ended before the window was filled (`InvalidData`)
+    #[cfg(feature = "embedded-io")]
+    Read(embedded_io::ErrorKind),
+    /// Communication error in the middle of a batched fill or image write
+    ///
+    /// `pixels_written` pixels of the window (in row-major order) were sent before
+    /// the failing transfer, so the remainder can be retried.
+    CommAt { source: E, pixels_written: u32 },
+}
+
+impl<E: core::fmt::Debug, PE: core::fmt::Debug> core::fmt::Display for Error<E, PE> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Error::Comm(source) => write!(f, "SPI error: {source:?}"),
+            Error::Pin(source) => write!(f, "pin error: {source:?}"),
+            Error::InvalidArgument => f.write_str("invalid argument"),
+            Error::Config(reason) => write!(f, "invalid config: {reason}"),
+            Error::NotInitialized => f.write_str("display not initialized"),
+            #[cfg(feature = "embedded-io")]
+            Error::Read(kind) => write!(f, "image read error: {kind:?}"),
+            Error::CommAt {
+                source,
+                pixels_written,
+            } => write!(
+                f,
+                "SPI error after {pixels_written} pixels of the window: {source:?}"
+            ),
+        }
+    }
+}
+
+impl<E, PE> Error<E, PE> {
+    /// Attach the progress of a batched write to a communication error
+    fn at(self, pixels_written: u32) -> Self {
+        match self {
+            Error::Comm(source) => Error::CommAt {
+                source,
+                pixels_written,
+            },
+            other => other,
+        }
+    }
+}
+
+/// Builder for [`GC9307C`], starting from [`Config::default()`]
+pub struct GC9307Builder<SPI, DC, RST, TIMER> {
+    config: Config,
+    _marker: core::marker::PhantomData<(SPI, DC, RST, TIMER)>,
+}
+
+impl<SPI, DC, RST, E, PE, TIMER> GC9307Builder<SPI, DC, RST, TIMER>
+where
+    SPI: SpiDevice<Error = E>,
+    DC: OutputPin<Error = PE>,
+    RST: OutputPin<Error = PE>,
+    TIMER: Timer,
+{
+    /// Start from an existing configuration
+    pub fn config(mut self, config: Config) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Color order: false=BGR, true=RGB
+    pub fn rgb(mut self, rgb: bool) -> Self {
+        self.config.rgb = rgb;
+        self
+    }
+
+    /// Display color inversion
+    pub fn inverted(mut self, inverted: bool) -> Self {
+        self.config.inverted = inverted;
+        self
+    }
+
+    /// Memory access orientation applied during `init`
+    pub fn orientation(mut self, orientation: Orientation) -> Self {
+        self.config.orientation = orientation;
+        self
+    }
+
+    /// Size and offset of a known module for the configured orientation
+    ///
+    /// Call after [`orientation`](Self::orientation).
+    pub fn panel(mut self, panel: Panel) -> Self {
+        self.config.apply_panel(panel);
+        self
+    }
+
+    /// Width and height in the configured orientation
+    pub fn size(mut self, width: u16, height: u16) -> Self {
+        self.config.width = width;
+        self.config.height = height;
+        self
+    }
+
+    /// X/Y coordinate offset of the visible area in GRAM
+    pub fn offset(mut self, dx: u16, dy: u16) -> Self {
+        self.config.dx = dx;
+        self.config.dy = dy;
+        self
+    }
+
+    /// Pixels per SPI write for solid fills
+    pub fn batch_pixels(mut self, batch_pixels: usize) -> Self {
+        self.config.batch_pixels = batch_pixels;
+        self
+    }
+
+    /// Preferred largest single SPI write, see [`Config::max_transfer_bytes`]
+    pub fn max_transfer_bytes(mut self, max_transfer_bytes: usize) -> Self {
+        self.config.max_transfer_bytes = Some(max_transfer_bytes);
+        self
+    }
+
+    /// Yield to the executor between pixel writes, see [`Config::yield_every_batches`]
+    pub fn yield_every_batches(mut self, batches: u32) -> Self {
+        self.config.yield_every_batches = Some(batches);
+        self
+    }
+
+    /// Create the configured driver
+    pub fn build(
+        self,
+        spi: SPI,
+        dc: DC,
+        rst: RST,
+        buffer: &mut [u8],
+    ) -> GC9307C<'_, SPI, DC, RST, TIMER> {
+        GC9307C::new(self.config, spi, dc, rst, buffer)
+    }
+}
+
+impl<SPI, DC, E, PE, TIMER> GC9307Builder<SPI, DC, NoPin<PE>, TIMER>
+where
+    SPI: SpiDevice<Error = E>,
+    DC: OutputPin<Error = PE>,
+    PE: embedded_hal::digital::Error,
+    TIMER: Timer,
+{
+    /// Create the configured driver for a board without a dedicated reset GPIO
+    pub fn build_without_reset(
+        self,
+        spi: SPI,
+        dc: DC,
+        buffer: &mut [u8],
+    ) -> GC9307C<'_, SPI, DC, NoPin<PE>, TIMER> {
+        GC9307C::new_without_reset(self.config, spi, dc, buffer)
+    }
+}
+
+/// Placeholder for an optional pin that is not connected
+///
+/// As a reset pin it makes [`GC9307C::reset`] fall back to a software reset (01h).
+/// `PE` is the pin error type it reports (it never fails); it defaults to
+/// [`Infallible`] and takes the DC pin's error type when that one can fail.
+pub struct NoPin<PE = Infallible>(core::marker::PhantomData<fn() -> PE>);
+
+impl<PE> NoPin<PE> {
+    /// Create the placeholder
+    pub const fn new() -> Self {
+        Self(core::marker::PhantomData)
+    }
+}
+
+impl<PE> Default for NoPin<PE> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<PE: embedded_hal::digital::Error> embedded_hal::digital::ErrorType for NoPin<PE> {
+    type Error = PE;
+}
+
+impl<PE: embedded_hal::digital::Error> OutputPin for NoPin<PE> {
+    fn set_low(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn set_high(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
 }
 
-pub struct GC9307C<'b, SPI, DC, RST, TIMER>
+pub struct GC9307C<'b, SPI, DC, RST, TIMER, TE = NoPin, BUF = &'b mut [u8], RSPI = SPI>
 where
     SPI: SpiDevice,
-    DC: OutputPin<Error = Infallible>,
-    RST: OutputPin<Error = Infallible>,
+    DC: OutputPin,
+    RST: OutputPin,
     TIMER: Timer,
 {
     spi: SPI,
     dc: DC,
-    rst: RST,
+    rst: Option<RST>,
     config: Config,
-    buffer: &'b mut [u8],
+    buffer: BUF,
+    /// Separate device, e.g. at a slower clock, for read commands
+    read_spi: Option<RSPI>,
     _timer: core::marker::PhantomData<TIMER>,
+    _buffer: core::marker::PhantomData<&'b mut [u8]>,
+    brightness_enabled: bool,
+    /// Color and pixel count the start of `buffer` currently holds, if it is a solid fill
+    prefilled: Option<(Rgb565, usize)>,
+    /// Whether `config` currently describes the panel with rows and columns exchanged
+    axes_exchanged: bool,
+    /// Memory Access Control (36h) value last written (or to be written by init)
+    madctl: u8,
+    /// Pixels still expected by the Memory Write opened by `begin_pixels`
+    window_remaining: u32,
+    /// Next position of the row-long Memory Write left open by `set_pixel`
+    pixel_cursor: Option<(u16, u16)>,
+    /// Top fixed lines and height of the vertical scroll area, as last defined
+    scroll_area: (u16, u16),
+    /// GRAM line last written with Vertical Scroll Start Address (37h)
+    scroll_line: u16,
+    /// Last bar drawn by `draw_progress_bar`, so a redraw only sends the change
+    progress_bar: Option<ProgressBar>,
+    /// TE output mode last set, re-sent by `reapply_modes`
+    tearing_effect: TearingEffect,
+    /// Whether init has completed since construction or the last reset
+    initialized: bool,
+    /// Pixel-data writes since the last yield, see `Config::yield_every_batches`
+    batches_since_yield: u32,
+    te: TE,
     #[cfg(feature = "software-rotation")]
     current_rotation: Rotation,
     #[cfg(feature = "software-rotation")]
@@ -200,25 +897,83 @@ where
     logical_height: u16,
 }
 
-#[maybe_async_cfg::maybe(
-    sync(cfg(not(feature = "async")), self = "GC9307C",),
-    async(feature = "async", keep_self)
-)]
-impl<'b, SPI, DC, RST, E, TIMER> GC9307C<'b, SPI, DC, RST, TIMER>
+impl<'b, SPI, DC, RST, E, PE, TIMER> GC9307C<'b, SPI, DC, RST, TIMER>
 where
     SPI: SpiDevice<Error = E>,
-    DC: OutputPin<Error = Infallible>,
-    RST: OutputPin<Error = Infallible>,
+    DC: OutputPin<Error = PE>,
+    RST: OutputPin<Error = PE>,
     TIMER: Timer,
 {
+    /// Create a driver; `buffer` is the working buffer all pixel data is sent from
+    ///
+    /// Place it in RAM your SPI DMA can reach (`BUF_SIZE` bytes is a good default, at
+    /// least 2 bytes are required). Only command bytes and their few parameters are
+    /// sent from elsewhere (stack, or flash for the init sequence).
     pub fn new(config: Config, spi: SPI, dc: DC, rst: RST, buffer: &'b mut [u8]) -> Self {
+        Self::with_optional_reset(config, spi, dc, Some(rst), buffer)
+    }
+
+    /// Create a driver that owns an `N`-byte working buffer, e.g.
+    /// `GC9307C::<_, _, _, MyTimer>::new_owned::<BUF_SIZE>(config, spi, dc, rst)`
+    ///
+    /// Avoids a `static mut` buffer and its lifetime when RAM allows keeping the buffer
+    /// inside the driver. The buffer moves with the driver, so with DMA make sure the
+    /// driver itself lives in DMA-reachable RAM.
+    pub fn new_owned<const N: usize>(
+        config: Config,
+        spi: SPI,
+        dc: DC,
+        rst: RST,
+    ) -> GC9307C<'static, SPI, DC, RST, TIMER, NoPin, [u8; N]> {
+        GC9307C::with_optional_reset(config, spi, dc, Some(rst), [0; N])
+    }
+
+    /// Create a builder with the default configuration
+    pub fn builder() -> GC9307Builder<SPI, DC, RST, TIMER> {
+        GC9307Builder {
+            config: Config::default(),
+            _marker: core::marker::PhantomData,
+        }
+    }
+}
+
+impl<SPI, DC, RST, E, PE, TIMER, BUF> GC9307C<'_, SPI, DC, RST, TIMER, NoPin, BUF>
+where
+    SPI: SpiDevice<Error = E>,
+    DC: OutputPin<Error = PE>,
+    RST: OutputPin<Error = PE>,
+    TIMER: Timer,
+    BUF: AsRef<[u8]> + AsMut<[u8]>,
+{
+    fn with_optional_reset(
+        config: Config,
+        spi: SPI,
+        dc: DC,
+        rst: Option<RST>,
+        buffer: BUF,
+    ) -> Self {
         Self {
             spi,
             dc,
             rst,
             config,
             buffer,
+            read_spi: None,
             _timer: core::marker::PhantomData,
+            _buffer: core::marker::PhantomData,
+            brightness_enabled: false,
+            prefilled: None,
+            axes_exchanged: config.orientation as u8 & MADCTL_MV != 0,
+            madctl: config.orientation.madctl(config.rgb),
+            window_remaining: 0,
+            pixel_cursor: None,
+            scroll_area: (0, MAX_ROW_END),
+            scroll_line: 0,
+            progress_bar: None,
+            tearing_effect: TearingEffect::VBlank,
+            initialized: false,
+            batches_since_yield: 0,
+            te: NoPin::<Infallible>::new(),
             #[cfg(feature = "software-rotation")]
             current_rotation: Rotation::Deg0,
             #[cfg(feature = "software-rotation")]
@@ -227,397 +982,2180 @@ where
             logical_height: config.height,
         }
     }
+}
 
-    pub async fn init(&mut self) -> Result<(), Error<E>> {
-        // Hardware reset first
-        self.reset().await?;
+impl<'b, SPI, DC, E, PE, TIMER> GC9307C<'b, SPI, DC, NoPin<PE>, TIMER>
+where
+    SPI: SpiDevice<Error = E>,
+    DC: OutputPin<Error = PE>,
+    PE: embedded_hal::digital::Error,
+    TIMER: Timer,
+{
+    /// Create a driver for a board whose RST line is not driven by a GPIO
+    ///
+    /// [`reset`](Self::reset) then issues a software reset (01h) instead of toggling a pin.
+    pub fn new_without_reset(config: Config, spi: SPI, dc: DC, buffer: &'b mut [u8]) -> Self {
+        Self::with_optional_reset(config, spi, dc, None, buffer)
+    }
+}
 
-        // Complete initialization sequence from docs/1.47寸IPS初始化GC9307+HSD.txt
-        // Enable extended register access
-        self.write_command(0xfe, &[]).await?;
-        self.write_command(0xef, &[]).await?;
-
-        // Memory access control and pixel format
-        self.write_command(0x36, &[0x48]).await?; // Memory access control
-        self.write_command(0x3a, &[0x05]).await?; // 16-bit color
-
-        // Power regulation settings (0x85-0x8F series)
-        self.write_command(0x85, &[0xc0]).await?;
-        self.write_command(0x86, &[0x98]).await?;
-        self.write_command(0x87, &[0x28]).await?;
-        self.write_command(0x89, &[0x33]).await?;
-        self.write_command(0x8B, &[0x84]).await?;
-        self.write_command(0x8D, &[0x3B]).await?;
-        self.write_command(0x8E, &[0x0f]).await?;
-        self.write_command(0x8F, &[0x70]).await?;
-
-        // Frame rate control
-        self.write_command(0xe8, &[0x13, 0x17]).await?;
-
-        // Additional power settings
-        self.write_command(0xec, &[0x57, 0x07, 0xff]).await?;
-        self.write_command(0xed, &[0x18, 0x09]).await?;
-        self.write_command(0xc9, &[0x10]).await?;
-
-        // Extended register settings
-        self.write_command(0xff, &[0x61]).await?;
-        self.write_command(0x99, &[0x3A]).await?;
-        self.write_command(0x9d, &[0x43]).await?;
-        self.write_command(0x98, &[0x3e]).await?;
-        self.write_command(0x9c, &[0x4b]).await?;
-
-        // Gamma correction settings (complete sequence)
-        self.write_command(0xF0, &[0x06, 0x08, 0x08, 0x06, 0x05, 0x1d])
-            .await?;
-        self.write_command(0xF2, &[0x00, 0x01, 0x09, 0x07, 0x04, 0x23])
-            .await?;
-        self.write_command(0xF1, &[0x3b, 0x68, 0x66, 0x36, 0x35, 0x2f])
-            .await?;
-        self.write_command(0xF3, &[0x37, 0x6a, 0x66, 0x37, 0x35, 0x35])
-            .await?;
+#[maybe_async_cfg::maybe(
+    sync(cfg(not(feature = "async")), self = "GC9307C",),
+    async(feature = "async", keep_self)
+)]
+impl<'b, SPI, DC, RST, E, PE, TIMER, TE, BUF, RSPI> GC9307C<'b, SPI, DC, RST, TIMER, TE, BUF, RSPI>
+where
+    SPI: SpiDevice<Error = E>,
+    DC: OutputPin<Error = PE>,
+    RST: OutputPin<Error = PE>,
+    TIMER: Timer,
+    BUF: AsRef<[u8]> + AsMut<[u8]>,
+    RSPI: SpiDevice<Error = E>,
+{
+    /// Attach the panel's tearing effect (TE) output for [`wait_for_vsync`](Self::wait_for_vsync)
+    pub fn with_te_pin<T>(self, te: T) -> GC9307C<'b, SPI, DC, RST, TIMER, T, BUF, RSPI> {
+        GC9307C {
+            spi: self.spi,
+            dc: self.dc,
+            rst: self.rst,
+            config: self.config,
+            buffer: self.buffer,
+            read_spi: self.read_spi,
+            _timer: self._timer,
+            _buffer: self._buffer,
+            brightness_enabled: self.brightness_enabled,
+            prefilled: self.prefilled,
+            axes_exchanged: self.axes_exchanged,
+            madctl: self.madctl,
+            window_remaining: self.window_remaining,
+            pixel_cursor: self.pixel_cursor,
+            scroll_area: self.scroll_area,
+            scroll_line: self.scroll_line,
+            progress_bar: self.progress_bar,
+            tearing_effect: self.tearing_effect,
+            initialized: self.initialized,
+            batches_since_yield: self.batches_since_yield,
+            te,
+            #[cfg(feature = "software-rotation")]
+            current_rotation: self.current_rotation,
+            #[cfg(feature = "software-rotation")]
+            logical_width: self.logical_width,
+            #[cfg(feature = "software-rotation")]
+            logical_height: self.logical_height,
+        }
+    }
 
-        // Additional display control registers
-        self.write_command(0xFA, &[0x80, 0x0f]).await?;
-        self.write_command(0xBE, &[0x11]).await?; // source bias
-        self.write_command(0xCB, &[0x02]).await?;
-        self.write_command(0xCD, &[0x22]).await?;
-        self.write_command(0x9B, &[0xFF]).await?;
+    /// Send read commands (ID, status, GRAM read-back) through `read_spi` instead
+    ///
+    /// The controller's read cycle is much slower than its write cycle, so a bus fast
+    /// enough for pixel writes corrupts read data. Pass a second `SpiDevice` on the same
+    /// bus and CS line configured for a slower clock (e.g. embassy-embedded-hal's
+    /// `SpiDeviceWithConfig`); writes keep using the fast device.
+    pub fn with_read_spi<R: SpiDevice<Error = E>>(
+        self,
+        read_spi: R,
+    ) -> GC9307C<'b, SPI, DC, RST, TIMER, TE, BUF, R> {
+        GC9307C {
+            spi: self.spi,
+            dc: self.dc,
+            rst: self.rst,
+            config: self.config,
+            buffer: self.buffer,
+            read_spi: Some(read_spi),
+            _timer: self._timer,
+            _buffer: self._buffer,
+            brightness_enabled: self.brightness_enabled,
+            prefilled: self.prefilled,
+            axes_exchanged: self.axes_exchanged,
+            madctl: self.madctl,
+            window_remaining: self.window_remaining,
+            pixel_cursor: self.pixel_cursor,
+            scroll_area: self.scroll_area,
+            scroll_line: self.scroll_line,
+            progress_bar: self.progress_bar,
+            tearing_effect: self.tearing_effect,
+            initialized: self.initialized,
+            batches_since_yield: self.batches_since_yield,
+            te: self.te,
+            #[cfg(feature = "software-rotation")]
+            current_rotation: self.current_rotation,
+            #[cfg(feature = "software-rotation")]
+            logical_width: self.logical_width,
+            #[cfg(feature = "software-rotation")]
+            logical_height: self.logical_height,
+        }
+    }
 
-        // Tearing effect
-        self.write_command(0x35, &[0x00]).await?;
-        self.write_command(0x44, &[0x00, 0x0a]).await?;
+    /// Destroy the driver and hand back the SPI device, DC pin and RST pin
+    ///
+    /// RST is `None` for a driver created without a reset pin. A borrowed buffer is free
+    /// again once this returns; the TE pin, the read device and an owned buffer are
+    /// dropped. The panel keeps showing its GRAM, so a new driver can take over without
+    /// running [`init`](Self::init) again, see
+    /// [`assume_initialized`](Self::assume_initialized).
+    pub fn release(self) -> (SPI, DC, Option<RST>) {
+        (self.spi, self.dc, self.rst)
+    }
 
-        // Sleep out and display on
-        self.write_command(0x11, &[]).await?; // Sleep out
-        TIMER::delay_ms(200).await; // Wait 200ms
+    /// Whether [`init`](Self::init) has completed since the driver was created or the
+    /// panel was last reset
+    ///
+    /// Drawing is refused with [`Error::NotInitialized`] while this is `false`.
+    pub fn is_initialized(&self) -> bool {
+        self.initialized
+    }
 
-        self.write_command(0x29, &[]).await?; // Display on
-        self.write_command(0x2c, &[]).await?; // Memory write
+    /// Treat the panel as initialized without running [`init`](Self::init), e.g. when a
+    /// bootloader or a [`release`](Self::release)d driver already set it up
+    pub fn assume_initialized(&mut self) {
+        self.initialized = true;
+    }
 
-        // Set initial orientation
-        self.set_orientation(self.config.orientation).await?;
-        Ok(())
+    pub async fn init(&mut self) -> Result<(), Error<E, PE>> {
+        self.init_with(DEFAULT_INIT_SEQUENCE).await
     }
 
-    pub async fn reset(&mut self) -> Result<(), Error<E>> {
-        self.rst.set_high().map_err(Error::Pin)?;
-        TIMER::delay_ms(10).await;
-        self.rst.set_low().map_err(Error::Pin)?;
-        TIMER::delay_ms(10).await;
-        self.rst.set_high().map_err(Error::Pin)?;
-        TIMER::delay_ms(120).await; // Wait for reset to complete
+    /// Initialize the display with a caller-supplied command sequence
+    ///
+    /// Performs a [`reset`](Self::reset) (a software reset when built without an RST pin),
+    /// sends every command of `sequence` (waiting `delay_ms` after each), then applies the
+    /// configured orientation and inversion. Sleep Out waits [`Config::sleepout_delay_ms`]
+    /// instead of its own delay. Fails with [`Error::Config`] before touching
+    /// the panel if [`Config::validate`] rejects the configuration.
+    ///
+    /// `sequence` is a `&[InitCommand]` or, with the `heapless` feature, a
+    /// `&`[`InitSequence`](crate::init_sequence::InitSequence) assembled at runtime.
+    pub async fn init_with<'s, S>(&mut self, sequence: S) -> Result<(), Error<E, PE>>
+    where
+        S: IntoIterator,
+        S::Item: Borrow<InitCommand<'s>>,
+    {
+        self.config.validate().map_err(Error::Config)?;
+
+        // Reset first
+        self.reset().await?;
+
+        for command in sequence {
+            let command = command.borrow();
+            self.write_command(command.cmd, command.params).await?;
+            let delay_ms = if command.cmd == Instruction::SleepOut as u8 {
+                self.config.sleepout_delay_ms
+            } else {
+                command.delay_ms
+            };
+            if delay_ms > 0 {
+                TIMER::delay_ms(delay_ms as u64).await;
+            }
+        }
 
+        // Set initial orientation and inversion
+        self.set_orientation(self.config.orientation).await?;
+        self.set_invert(self.config.inverted).await?;
+        self.initialized = true;
         Ok(())
     }
 
-    pub async fn set_orientation(&mut self, orientation: Orientation) -> Result<(), Error<E>> {
-        if self.config.rgb {
-            self.write_command(0x36, &[orientation as u8]).await?;
+    /// Reset the panel: toggles the RST pin, or sends a software reset (01h) without one
+    pub async fn reset(&mut self) -> Result<(), Error<E, PE>> {
+        if let Some(rst) = self.rst.as_mut() {
+            rst.set_high().map_err(Error::Pin)?;
+            TIMER::delay_ms(10).await;
+            rst.set_low().map_err(Error::Pin)?;
+            TIMER::delay_ms(10).await;
+            rst.set_high().map_err(Error::Pin)?;
+            TIMER::delay_ms(self.config.reset_delay_ms as u64).await; // Wait for reset to complete
+            self.brightness_enabled = false; // Reset clears Write CTRL Display
+            self.initialized = false;
+            Ok(())
         } else {
-            self.write_command(0x36, &[orientation as u8 | 0x08])
-                .await?;
+            self.soft_reset().await
         }
+    }
+
+    /// Software reset (01h): restores register defaults without touching the RST pin
+    ///
+    /// Useful to recover a wedged panel, or on boards where RST is shared or not wired.
+    /// GRAM content is undefined afterwards and the panel is back in sleep mode, so
+    /// re-run [`init`](Self::init) before drawing.
+    pub async fn soft_reset(&mut self) -> Result<(), Error<E, PE>> {
+        self.command(Instruction::SoftwareReset, &[]).await?;
+        TIMER::delay_ms(self.config.reset_delay_ms as u64).await; // Wait for reset to complete
+        self.brightness_enabled = false; // Reset clears Write CTRL Display
+        self.initialized = false;
+
+        Ok(())
+    }
+
+    /// Write MADCTL (36h) for `orientation` combined with the configured color order
+    ///
+    /// Switching between portrait and landscape presets swaps `width`/`height` and moves
+    /// the panel offset between `dx` and `dy`, so the image stays on the visible area.
+    pub async fn set_orientation(&mut self, orientation: Orientation) -> Result<(), Error<E, PE>> {
+        let madctl = orientation.madctl(self.config.rgb);
+        self.command(Instruction::MemoryAccessControl, &[madctl])
+            .await?;
+        self.madctl = madctl;
         self.config.orientation = orientation;
+        self.set_axes_exchanged(madctl & MADCTL_MV != 0);
         Ok(())
     }
 
-    /// Write command with optional parameters
-    async fn write_command(&mut self, cmd: u8, params: &[u8]) -> Result<(), Error<E>> {
-        // Set DC low for command
-        self.dc.set_low().map_err(Error::Pin)?;
-        self.spi.write(&[cmd]).await.map_err(Error::Comm)?;
+    /// Rotate the panel in hardware through the MADCTL (36h) MX/MY/MV bits
+    ///
+    /// Available with or without the `software-rotation` feature, and without its
+    /// per-pixel transform. `Deg0` is the native portrait orientation (172×320).
+    /// Width/height and the 34px panel offset are swapped between axes for 90°/270°;
+    /// the offset is centered in GRAM, so 180°/270° keep the same value.
+    ///
+    /// | Rotation | MADCTL (RGB)        | Size    |
+    /// |----------|---------------------|---------|
+    /// | `Deg0`   | `0x40` (MX)         | 172×320 |
+    /// | `Deg90`  | `0x20` (MV)         | 320×172 |
+    /// | `Deg180` | `0x80` (MY)         | 172×320 |
+    /// | `Deg270` | `0xE0` (MY, MX, MV) | 320×172 |
+    pub async fn set_hw_rotation(&mut self, rotation: Rotation) -> Result<(), Error<E, PE>> {
+        self.set_orientation(rotation.orientation()).await
+    }
 
-        // Write parameters if any
-        if !params.is_empty() {
-            self.dc.set_high().map_err(Error::Pin)?;
-            self.spi.write(params).await.map_err(Error::Comm)?;
+    /// Write a raw Memory Access Control (36h) value for mirror/flip combinations the
+    /// [`Orientation`] presets don't cover (e.g. `0xC8`: MY | MX | BGR)
+    ///
+    /// The cached state follows the value: `rgb` from the BGR bit (0x08), width/height
+    /// and the panel offset from the MV bit (0x20), and the orientation when the value
+    /// matches a preset.
+    pub async fn set_madctl(&mut self, value: u8) -> Result<(), Error<E, PE>> {
+        self.command(Instruction::MemoryAccessControl, &[value])
+            .await?;
+
+        self.madctl = value;
+        self.config.rgb = value & MADCTL_BGR == 0;
+        let preset = match value & !MADCTL_BGR {
+            0x40 => Some(Orientation::Portrait),
+            0x20 => Some(Orientation::Landscape),
+            0x80 => Some(Orientation::PortraitSwapped),
+            0xE0 => Some(Orientation::LandscapeSwapped),
+            _ => None,
+        };
+        if let Some(orientation) = preset {
+            self.config.orientation = orientation;
         }
+        self.set_axes_exchanged(value & MADCTL_MV != 0);
+
         Ok(())
     }
 
-    /// Write raw pixel data to display (data mode)
-    async fn write_raw_data(&mut self, data: &[u8]) -> Result<(), Error<E>> {
-        self.dc.set_high().map_err(Error::Pin)?;
-        self.spi.write(data).await.map_err(Error::Comm)
+    /// Switch between RGB (`true`) and BGR color order at runtime
+    ///
+    /// Re-writes MADCTL (36h) with only the BGR bit changed, keeping the current
+    /// orientation or custom [`set_madctl`](Self::set_madctl) value; e.g. to apply a
+    /// per-unit setting found during calibration.
+    pub async fn set_color_order(&mut self, rgb: bool) -> Result<(), Error<E, PE>> {
+        let bgr = if rgb { 0 } else { MADCTL_BGR };
+        self.set_madctl(self.madctl & !MADCTL_BGR | bgr).await
+    }
+
+    /// MADCTL (36h) byte last written by the driver, covering orientation, custom
+    /// [`set_madctl`](Self::set_madctl) values and color order
+    ///
+    /// Before [`init`](Self::init) this is the value init will write. Raw writes
+    /// through [`command`](Self::command) are not tracked.
+    pub fn madctl(&self) -> u8 {
+        self.madctl
     }
 
-    /// Fill entire screen with a single color (optimized batch implementation)
-    pub async fn fill_screen(&mut self, color: Rgb565) -> Result<(), Error<E>> {
+    /// Drawable `(width, height)` in the coordinates the drawing calls take
+    ///
+    /// This is the logical size after software rotation when `software-rotation` is
+    /// enabled, and the configured (orientation-adjusted) size otherwise.
+    pub fn dimensions(&self) -> (u16, u16) {
         #[cfg(feature = "software-rotation")]
-        let (width, height) = (self.logical_width, self.logical_height);
+        let dimensions = (self.logical_width, self.logical_height);
         #[cfg(not(feature = "software-rotation"))]
-        let (width, height) = (self.config.width, self.config.height);
+        let dimensions = (self.config.width, self.config.height);
 
-        self.set_address_window(0, 0, width - 1, height - 1).await?;
+        dimensions
+    }
 
-        let color_raw = RawU16::from(color).into_inner();
-        let color_bytes = color_raw.to_be_bytes(); // Use big-endian for correct color display
+    /// Drawable area as an embedded-graphics [`Rectangle`] at the origin
+    pub fn bounding_box(&self) -> Rectangle {
+        let (width, height) = self.dimensions();
+        Rectangle::new(Point::zero(), Size::new(width as u32, height as u32))
+    }
 
-        // Calculate total pixels
-        let total_pixels = self.config.width as u32 * self.config.height as u32;
+    /// Swap width/height and dx/dy when the MV (row/column exchange) state changes
+    fn set_axes_exchanged(&mut self, exchanged: bool) {
+        if exchanged == self.axes_exchanged {
+            return;
+        }
+        self.axes_exchanged = exchanged;
+        self.end_memory_write();
 
-        // Use batch transmission for better performance
-        const BATCH_SIZE: usize = 512; // Send 512 pixels at a time
-        let mut batch_buffer = [0u8; BATCH_SIZE * 2]; // 2 bytes per pixel
+        let config = &mut self.config;
+        (config.width, config.height) = (config.height, config.width);
+        (config.dx, config.dy) = (config.dy, config.dx);
 
-        // Fill batch buffer with color
-        for i in 0..BATCH_SIZE {
-            batch_buffer[i * 2] = color_bytes[0];
-            batch_buffer[i * 2 + 1] = color_bytes[1];
-        }
+        // Keep software rotation's logical dimensions in sync with the new size
+        #[cfg(feature = "software-rotation")]
+        self.set_rotation(self.current_rotation);
+    }
 
-        // Send full batches
-        let full_batches = total_pixels / BATCH_SIZE as u32;
-        for _ in 0..full_batches {
-            self.write_raw_data(&batch_buffer).await?;
-        }
+    /// Enable or disable display color inversion
+    pub async fn set_invert(&mut self, invert: bool) -> Result<(), Error<E, PE>> {
+        let instruction = if invert {
+            Instruction::DisplayInversionOn
+        } else {
+            Instruction::DisplayInversionOff
+        };
+        self.command(instruction, &[]).await?;
+        self.config.inverted = invert;
+        Ok(())
+    }
 
-        // Send remaining pixels
-        let remaining_pixels = (total_pixels % BATCH_SIZE as u32) as usize;
-        if remaining_pixels > 0 {
-            let remaining_bytes = remaining_pixels * 2;
-            self.write_raw_data(&batch_buffer[..remaining_bytes])
-                .await?;
-        }
+    /// Enter sleep mode (Sleep In) to reduce power consumption
+    pub async fn sleep(&mut self) -> Result<(), Error<E, PE>> {
+        self.command(Instruction::SleepIn, &[]).await?;
+        TIMER::delay_ms(120).await; // Required before the next Sleep Out
+        Ok(())
+    }
 
+    /// Leave sleep mode (Sleep Out), keeping the current GRAM contents
+    pub async fn wake(&mut self) -> Result<(), Error<E, PE>> {
+        self.command(Instruction::SleepOut, &[]).await?;
+        TIMER::delay_ms(120).await; // Wait for the supply voltages to stabilize
         Ok(())
     }
 
-    /// Fill a rectangular area with a color (optimized batch implementation)
-    pub async fn fill_rect(
+    /// Enter the lowest-power standby: Display Off, Sleep In, then optionally VCore
+    ///
+    /// `vcore` holds raw VCore Voltage Regulation (A7h) parameters for a lowered core
+    /// voltage, from the panel vendor's settings; `None` leaves VCore unchanged. Undo
+    /// with [`exit_standby`](Self::exit_standby), which also waits out the 120ms the
+    /// controller needs between Sleep In and Sleep Out.
+    pub async fn enter_standby(&mut self, vcore: Option<&[u8]>) -> Result<(), Error<E, PE>> {
+        self.display_off().await?;
+        self.sleep().await?;
+        if let Some(params) = vcore {
+            self.command(Instruction::VcoreVoltageControl, params)
+                .await?;
+        }
+        Ok(())
+    }
+
+    /// Leave standby in reverse order: restore VCore, Sleep Out, wait, Display On
+    ///
+    /// `vcore` holds the A7h parameters to restore if [`enter_standby`](Self::enter_standby)
+    /// lowered it. GRAM contents are kept, so the last frame reappears.
+    pub async fn exit_standby(&mut self, vcore: Option<&[u8]>) -> Result<(), Error<E, PE>> {
+        if let Some(params) = vcore {
+            self.command(Instruction::VcoreVoltageControl, params)
+                .await?;
+        }
+        self.command(Instruction::SleepOut, &[]).await?;
+        TIMER::delay_ms(self.config.sleepout_delay_ms as u64).await;
+        self.display_on().await
+    }
+
+    /// Turn the panel output on, showing the GRAM contents
+    pub async fn display_on(&mut self) -> Result<(), Error<E, PE>> {
+        self.command(Instruction::DisplayOn, &[]).await
+    }
+
+    /// Turn the panel output off; GRAM can still be written while off
+    pub async fn display_off(&mut self) -> Result<(), Error<E, PE>> {
+        self.command(Instruction::DisplayOff, &[]).await
+    }
+
+    /// Set the backlight brightness through the DISBV register (0 = off, 255 = full)
+    ///
+    /// Only effective on modules whose backlight is driven by the controller.
+    /// The brightness control block is enabled on the first call.
+    pub async fn set_brightness(&mut self, level: u8) -> Result<(), Error<E, PE>> {
+        if !self.brightness_enabled {
+            // BCTRL | DD | BL: enable brightness control, dimming and backlight
+            self.command(Instruction::WriteCtrlDisplay, &[0x2C]).await?;
+            self.brightness_enabled = true;
+        }
+        self.command(Instruction::WriteDisplayBrightness, &[level])
+            .await
+    }
+
+    /// Define the hardware vertical scroll area (in GRAM lines)
+    ///
+    /// `top_fixed + scroll_height + bottom_fixed` should equal the GRAM height (320).
+    pub async fn set_scroll_area(
+        &mut self,
+        top_fixed: u16,
+        scroll_height: u16,
+        bottom_fixed: u16,
+    ) -> Result<(), Error<E, PE>> {
+        let [tfa_hi, tfa_lo] = top_fixed.to_be_bytes();
+        let [vsa_hi, vsa_lo] = scroll_height.to_be_bytes();
+        let [bfa_hi, bfa_lo] = bottom_fixed.to_be_bytes();
+        self.command(
+            Instruction::VerticalScrollingDefinition,
+            &[tfa_hi, tfa_lo, vsa_hi, vsa_lo, bfa_hi, bfa_lo],
+        )
+        .await?;
+        self.scroll_area = (top_fixed, scroll_height);
+        Ok(())
+    }
+
+    /// Define the vertical scroll area from its fixed bands only
+    ///
+    /// The scroll height is derived as `320 - top_fixed - bottom_fixed`, so the three
+    /// parts always add up to the panel's GRAM height. That is the physical line count
+    /// in every orientation, not the logical height; the 34px panel offset lies on the
+    /// column axis and does not enter the sum. Fails with [`Error::InvalidArgument`]
+    /// if the fixed bands together exceed 320 lines.
+    pub async fn enable_scroll(
+        &mut self,
+        top_fixed: u16,
+        bottom_fixed: u16,
+    ) -> Result<(), Error<E, PE>> {
+        let scroll_height = top_fixed
+            .checked_add(bottom_fixed)
+            .and_then(|fixed| MAX_ROW_END.checked_sub(fixed))
+            .ok_or(Error::InvalidArgument)?;
+        self.set_scroll_area(top_fixed, scroll_height, bottom_fixed)
+            .await
+    }
+
+    /// Set the GRAM line shown at the top of the scroll area
+    pub async fn scroll_to(&mut self, line: u16) -> Result<(), Error<E, PE>> {
+        self.command(Instruction::VerticalScrollStartAddress, &line.to_be_bytes())
+            .await?;
+        self.scroll_line = line;
+        Ok(())
+    }
+
+    /// Move the scroll pointer by `delta` lines, wrapping within the scroll area
+    ///
+    /// Positive values scroll content up (the next GRAM line comes into view at the
+    /// bottom). Starts from the line last set by [`scroll_to`](Self::scroll_to), and
+    /// uses the whole GRAM as scroll area until [`set_scroll_area`](Self::set_scroll_area)
+    /// is called.
+    pub async fn scroll_by(&mut self, delta: i16) -> Result<(), Error<E, PE>> {
+        let (top_fixed, scroll_height) = self.scroll_area;
+        if scroll_height == 0 {
+            return Err(Error::InvalidArgument);
+        }
+
+        let offset = self.scroll_line as i32 - top_fixed as i32 + delta as i32;
+        let line = top_fixed + offset.rem_euclid(scroll_height as i32) as u16;
+        self.scroll_to(line).await
+    }
+
+    /// Read the display identification (04h): manufacturer ID, version, and module ID
+    ///
+    /// Requires the panel's SDO line to be wired to the MCU (4-wire SPI with MISO).
+    pub async fn read_id(&mut self) -> Result<[u8; 3], Error<E, PE>> {
+        let mut response = [0u8; 4];
+        self.read_command(Instruction::ReadDisplayId as u8, &mut response)
+            .await?;
+        // First byte is a dummy read
+        Ok([response[1], response[2], response[3]])
+    }
+
+    /// Read the display status register (09h) as a big-endian 32-bit word
+    ///
+    /// Useful to detect a panel that silently dropped off the bus or lost its state
+    /// (e.g. sleep or display-off bits set unexpectedly).
+    pub async fn read_status(&mut self) -> Result<u32, Error<E, PE>> {
+        let mut response = [0u8; 5];
+        self.read_command(Instruction::ReadDisplayStatus as u8, &mut response)
+            .await?;
+        // First byte is a dummy read
+        Ok(u32::from_be_bytes([
+            response[1],
+            response[2],
+            response[3],
+            response[4],
+        ]))
+    }
+
+    /// Set the start and end GRAM rows (inclusive) shown in partial display mode
+    pub async fn set_partial_area(&mut self, start: u16, end: u16) -> Result<(), Error<E, PE>> {
+        let [start_hi, start_lo] = start.to_be_bytes();
+        let [end_hi, end_lo] = end.to_be_bytes();
+        self.command(
+            Instruction::PartialArea,
+            &[start_hi, start_lo, end_hi, end_lo],
+        )
+        .await
+    }
+
+    /// Enter partial display mode; only the partial area is refreshed
+    pub async fn partial_mode_on(&mut self) -> Result<(), Error<E, PE>> {
+        self.command(Instruction::PartialModeOn, &[]).await
+    }
+
+    /// Return to normal (full-screen) display mode
+    pub async fn normal_mode_on(&mut self) -> Result<(), Error<E, PE>> {
+        self.command(Instruction::NormalDisplayOn, &[]).await
+    }
+
+    /// Write the four gamma correction tables (F0h..F3h)
+    ///
+    /// [`init`](Self::init) writes [`DEFAULT_GAMMA`]; call this afterwards to apply a
+    /// per-unit calibration, e.g. a table stored in NVM.
+    pub async fn set_gamma(
+        &mut self,
+        g1: &[u8; 6],
+        g2: &[u8; 6],
+        g3: &[u8; 6],
+        g4: &[u8; 6],
+    ) -> Result<(), Error<E, PE>> {
+        self.command(Instruction::GammaSet1, g1).await?;
+        self.command(Instruction::GammaSet2, g2).await?;
+        self.command(Instruction::GammaSet3, g3).await?;
+        self.command(Instruction::GammaSet4, g4).await
+    }
+
+    /// Write VREG1A (C3h) and VREG1B (C4h), which set the VCOM level on this controller
+    ///
+    /// Small changes tune out faint flicker on individual units; store the values per
+    /// unit (e.g. in NVM) and apply them after [`init`](Self::init). This overrides the
+    /// factory OTP calibration until the next reset, see [`DEFAULT_VREG`].
+    pub async fn set_vreg(&mut self, vreg1a: u8, vreg1b: u8) -> Result<(), Error<E, PE>> {
+        self.command(Instruction::Vreg1aControl, &[vreg1a]).await?;
+        self.command(Instruction::Vreg1bControl, &[vreg1b]).await
+    }
+
+    /// Set the refresh rate through Frame Rate Control (E8h) and Blanking Porch
+    /// Control (B5h)
+    ///
+    /// - `rtna` (0..=15): clocks per line; each step lengthens the line period.
+    /// - `fpa` / `bpa` (2..=127): front/back porch in lines around the active area.
+    ///
+    /// The frame period is the line period times `320 + fpa + bpa` lines, so larger
+    /// values lower the rate; [`DEFAULT_INIT_SEQUENCE`] uses `rtna = 3`. Lower rates save
+    /// power and EMI but may flicker; check the result on the actual panel.
+    pub async fn set_frame_rate(&mut self, rtna: u8, fpa: u8, bpa: u8) -> Result<(), Error<E, PE>> {
+        if rtna > 0x0F || !(2..=127).contains(&fpa) || !(2..=127).contains(&bpa) {
+            return Err(Error::InvalidArgument);
+        }
+
+        // Keep the default inversion mode (upper nibble) and second parameter
+        self.command(Instruction::FrameRateControl, &[0x10 | rtna, 0x17])
+            .await?;
+        self.command(Instruction::BlankingPorchControl, &[fpa, bpa])
+            .await
+    }
+
+    /// Write SPI Interface Control (E9h, "SPI 2data control")
+    ///
+    /// [`DEFAULT_INIT_SEQUENCE`] leaves it at the reset value. Common values:
+    ///
+    /// - `0x00`: reset default, one data line (the usual 4-wire SPI with D/CX)
+    /// - `0x08`: `2data_en`, pixel data on two lines; bits 2..0 (`2data_mdt`) then
+    ///   select the 2-lane pixel format, see the GC9307 datasheet
+    ///
+    /// Some modules need an explicit write before data bytes line up; to apply it
+    /// from the start, add `InitCommand::new(0xE9, &[cfg])` to a custom sequence
+    /// instead (see [`init_with`](Self::init_with)).
+    pub async fn set_spi_interface(&mut self, cfg: u8) -> Result<(), Error<E, PE>> {
+        self.command(Instruction::SpiInterfaceControl, &[cfg]).await
+    }
+
+    /// Enter (`true`) or leave (`false`) idle mode
+    ///
+    /// Idle mode reduces the panel to 8 colors (the MSB of each channel) for
+    /// low-power standby; GRAM content is unaffected.
+    pub async fn idle_mode(&mut self, on: bool) -> Result<(), Error<E, PE>> {
+        let cmd = if on {
+            Instruction::IdleModeOn
+        } else {
+            Instruction::IdleModeOff
+        };
+        self.command(cmd, &[]).await
+    }
+
+    /// Configure the tearing effect (TE) output
+    ///
+    /// `VAndHBlank` additionally pulses TE during every horizontal blanking period,
+    /// for syncing partial updates to the scan line.
+    pub async fn set_tearing_effect(&mut self, mode: TearingEffect) -> Result<(), Error<E, PE>> {
+        self.tearing_effect = mode;
+        match mode {
+            TearingEffect::Off => self.command(Instruction::TearingEffectDisable, &[]).await,
+            TearingEffect::VBlank => {
+                self.command(Instruction::TearingEffectEnable, &[0x00])
+                    .await
+            }
+            TearingEffect::VAndHBlank => {
+                self.command(Instruction::TearingEffectEnable, &[0x01])
+                    .await
+            }
+        }
+    }
+
+    /// Re-send the mode registers without resetting the panel or touching GRAM
+    ///
+    /// A fast recovery path for a brownout that lost the controller's mode state but
+    /// kept the picture: writes the cached MADCTL (36h, see [`madctl`](Self::madctl)),
+    /// 16-bit pixel format (3Ah), inversion from the config and the TE mode last set
+    /// with [`set_tearing_effect`](Self::set_tearing_effect) (V-blank, as init leaves
+    /// it, by default). Unlike [`init`](Self::init) there is no reset, power or gamma
+    /// sequence and no Sleep Out delay.
+    pub async fn reapply_modes(&mut self) -> Result<(), Error<E, PE>> {
+        self.command(Instruction::MemoryAccessControl, &[self.madctl])
+            .await?;
+        self.command(Instruction::PixelFormatSet, &[0x05]).await?;
+        self.set_invert(self.config.inverted).await?;
+        self.set_tearing_effect(self.tearing_effect).await
+    }
+
+    /// Recover a clean command boundary after a drawing future was dropped midway
+    ///
+    /// Every drawing call starts by re-addressing its window with fresh commands, and
+    /// D/CX is driven low for each command byte, so a cancelled fill never makes later
+    /// commands be taken as pixel data; it only leaves part of its area undrawn.
+    /// This sends a NOP (00h) to end the interrupted Memory Write explicitly, and drops
+    /// the driver's cached stream state (open [`begin_pixels`](Self::begin_pixels)
+    /// stream, prefilled buffer). Redraw the affected area afterwards.
+    pub async fn reset_write_state(&mut self) -> Result<(), Error<E, PE>> {
+        self.prefilled = None;
+        self.command(Instruction::Nop, &[]).await
+    }
+
+    /// Send a command with its parameters
+    ///
+    /// Fails with [`Error::InvalidArgument`] if `params` does not match the command's
+    /// [`param_len`](Instruction::param_len).
+    pub async fn command(
+        &mut self,
+        instruction: Instruction,
+        params: &[u8],
+    ) -> Result<(), Error<E, PE>> {
+        if instruction
+            .param_len()
+            .is_some_and(|len| len != params.len())
+        {
+            return Err(Error::InvalidArgument);
+        }
+        self.write_command(instruction as u8, params).await
+    }
+
+    /// Write command with optional parameters
+    ///
+    /// Command and parameters are two bus transactions: `SpiDevice::transaction` has no
+    /// operation to flip D/CX between them, so they cannot share one CS assertion.
+    async fn write_command(&mut self, cmd: u8, params: &[u8]) -> Result<(), Error<E, PE>> {
+        self.end_memory_write();
+        // Set DC low for command
+        self.dc.set_low().map_err(Error::Pin)?;
+        self.spi.write(&[cmd]).await.map_err(Error::Comm)?;
+
+        // Write parameters if any
+        if !params.is_empty() {
+            self.dc.set_high().map_err(Error::Pin)?;
+            self.spi.write(params).await.map_err(Error::Comm)?;
+        }
+        Ok(())
+    }
+
+    /// Forget streams that continue a Memory Write; any command ends it
+    fn end_memory_write(&mut self) {
+        self.window_remaining = 0;
+        self.pixel_cursor = None;
+    }
+
+    /// Send a read command and clock its response (starting with the dummy byte) into `buf`
+    async fn read_command(&mut self, cmd: u8, buf: &mut [u8]) -> Result<(), Error<E, PE>> {
+        self.end_memory_write();
+        self.dc.set_low().map_err(Error::Pin)?;
+        // CS has to stay asserted from the command byte through the response. D/CX is
+        // not sampled while the controller drives SDO, so it can stay low for the read.
+        let operations = &mut [Operation::Write(&[cmd]), Operation::Read(buf)];
+        match &mut self.read_spi {
+            Some(read_spi) => read_spi.transaction(operations).await,
+            None => self.spi.transaction(operations).await,
+        }
+        .map_err(Error::Comm)
+    }
+
+    /// Write the first `len` bytes of the working buffer as pixel data
+    ///
+    /// Every pixel write goes through here, so pixel data is only ever sent from the
+    /// caller-provided buffer (which can be placed in DMA-capable RAM).
+    async fn write_buffer(&mut self, len: usize) -> Result<(), Error<E, PE>> {
+        self.dc.set_high().map_err(Error::Pin)?;
+        self.spi
+            .write(&self.buffer.as_ref()[..len])
+            .await
+            .map_err(Error::Comm)?;
+
+        if let Some(every) = self.config.yield_every_batches {
+            self.batches_since_yield += 1;
+            if self.batches_since_yield >= every {
+                self.batches_since_yield = 0;
+                yield_now().await;
+            }
+        }
+        Ok(())
+    }
+
+    /// Write a horizontal run of `len` buffered pixels starting at (x, y)
+    async fn write_run(&mut self, x: u16, y: u16, len: usize) -> Result<(), Error<E, PE>> {
+        self.set_address_window(x, y, x + len as u16 - 1, y).await?;
+
+        // 180° and 270° map a left-to-right logical run onto a descending GRAM run
+        #[cfg(feature = "software-rotation")]
+        if matches!(self.current_rotation, Rotation::Deg180 | Rotation::Deg270) {
+            let run = &mut self.buffer.as_mut()[..len * 2];
+            run.reverse();
+            for pixel in run.chunks_exact_mut(2) {
+                pixel.swap(0, 1);
+            }
+        }
+
+        self.write_buffer(len * 2).await
+    }
+
+    /// Number of pixels sent per SPI write by solid fills
+    fn batch_pixels(&self) -> usize {
+        let preferred = match self.config.max_transfer_bytes {
+            Some(bytes) => bytes / 2,
+            None => self.config.batch_pixels,
+        };
+        preferred.min(self.buffer.as_ref().len() / 2).max(1)
+    }
+
+    /// Largest number of pixels streamed per SPI write from the working buffer
+    fn transfer_pixels(&self) -> usize {
+        let capacity = self.buffer.as_ref().len() / 2;
+        match self.config.max_transfer_bytes {
+            Some(bytes) => (bytes / 2).clamp(1, capacity.max(1)),
+            None => capacity,
+        }
+    }
+
+    /// Stream `total_pixels` of a single color, batching through the working buffer
+    async fn write_repeated(
+        &mut self,
+        color: Rgb565,
+        total_pixels: u32,
+    ) -> Result<(), Error<E, PE>> {
+        self.write_repeated_with_progress(color, total_pixels, |_, _| {})
+            .await
+    }
+
+    /// [`write_repeated`](Self::write_repeated), calling `progress(done, total)` after
+    /// every batch
+    async fn write_repeated_with_progress<F: FnMut(u32, u32)>(
+        &mut self,
+        color: Rgb565,
+        total_pixels: u32,
+        mut progress: F,
+    ) -> Result<(), Error<E, PE>> {
+        // Fills that fit the buffer go out in a single write
+        let batch = if total_pixels as usize <= self.transfer_pixels() {
+            (total_pixels as usize).max(1)
+        } else {
+            self.batch_pixels()
+        };
+        let prefill = batch.min(total_pixels as usize);
+
+        // Fill the batch once, then send it repeatedly; the fill is kept across calls
+        // until another drawing path reuses the buffer
+        if !matches!(self.prefilled, Some((c, len)) if c == color && len >= prefill) {
+            let color_bytes = color_bytes(color);
+            for pixel in self.buffer.as_mut()[..prefill * 2].chunks_exact_mut(2) {
+                pixel.copy_from_slice(&color_bytes);
+            }
+            self.prefilled = Some((color, prefill));
+        }
+
+        let mut remaining = total_pixels;
+        while remaining > 0 {
+            let pixels = remaining.min(batch as u32);
+            self.write_buffer(pixels as usize * 2)
+                .await
+                .map_err(|e| e.at(total_pixels - remaining))?;
+            remaining -= pixels;
+            progress(total_pixels - remaining, total_pixels);
+        }
+
+        Ok(())
+    }
+
+    /// Draw individual pixels, batching horizontally adjacent runs into one window write
+    async fn draw_pixels<I>(&mut self, pixels: I) -> Result<(), Error<E, PE>>
+    where
+        I: IntoIterator<Item = Pixel<Rgb565>>,
+    {
+        #[cfg(feature = "software-rotation")]
+        let (width, height) = (self.logical_width, self.logical_height);
+        #[cfg(not(feature = "software-rotation"))]
+        let (width, height) = (self.config.width, self.config.height);
+
+        let capacity = self.transfer_pixels();
+        let (mut run_x, mut run_y, mut run_len) = (0u16, 0u16, 0usize);
+        self.prefilled = None;
+
+        for Pixel(point, color) in pixels {
+            if point.x < 0 || point.y < 0 || point.x >= width as i32 || point.y >= height as i32 {
+                continue; // Outside screen bounds
+            }
+            let (x, y) = (point.x as u16, point.y as u16);
+
+            // Flush the pending run unless this pixel directly extends it
+            if run_len > 0 && (y != run_y || x != run_x + run_len as u16 || run_len == capacity) {
+                self.write_run(run_x, run_y, run_len).await?;
+                run_len = 0;
+            }
+            if run_len == 0 {
+                run_x = x;
+                run_y = y;
+            }
+
+            let color_bytes = color_bytes(color);
+            self.buffer.as_mut()[run_len * 2..run_len * 2 + 2].copy_from_slice(&color_bytes);
+            run_len += 1;
+        }
+
+        if run_len > 0 {
+            self.write_run(run_x, run_y, run_len).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Fill a signed rectangle with a single color after clipping it to the screen
+    async fn fill_solid_area(
+        &mut self,
+        area: &Rectangle,
+        color: Rgb565,
+    ) -> Result<(), Error<E, PE>> {
+        #[cfg(feature = "software-rotation")]
+        let (width, height) = (self.logical_width, self.logical_height);
+        #[cfg(not(feature = "software-rotation"))]
+        let (width, height) = (self.config.width, self.config.height);
+
+        let screen = Rectangle::new(Point::zero(), Size::new(width as u32, height as u32));
+        let drawable = area.intersection(&screen);
+        if drawable.is_zero_sized() {
+            return Ok(()); // Nothing to draw
+        }
+
+        self.fill_rect(
+            drawable.top_left.x as u16,
+            drawable.top_left.y as u16,
+            drawable.size.width as u16,
+            drawable.size.height as u16,
+            color,
+        )
+        .await
+    }
+
+    /// Plot quarter arcs of radius `r` around the corners (left, top)..(right, bottom)
+    ///
+    /// With `left == right` and `top == bottom` this is a full circle.
+    async fn draw_arcs(
+        &mut self,
+        left: i32,
+        right: i32,
+        top: i32,
+        bottom: i32,
+        r: i32,
+        color: Rgb565,
+    ) -> Result<(), Error<E, PE>> {
+        let (mut x, mut y, mut err) = (r, 0i32, 1 - r);
+
+        // Midpoint circle: walk one octant and mirror it into the other seven
+        while x >= y {
+            let points = [
+                (right + x, bottom + y),
+                (right + y, bottom + x),
+                (left - y, bottom + x),
+                (left - x, bottom + y),
+                (left - x, top - y),
+                (left - y, top - x),
+                (right + y, top - x),
+                (right + x, top - y),
+            ];
+            self.draw_pixels(
+                points
+                    .into_iter()
+                    .map(|(px, py)| Pixel(Point::new(px, py), color)),
+            )
+            .await?;
+
+            y += 1;
+            if err < 0 {
+                err += 2 * y + 1;
+            } else {
+                x -= 1;
+                err += 2 * (y - x) + 1;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Fill the rounded caps of radius `r` above `top` and below `bottom`, spanning
+    /// `left - dx..=right + dx`; each row is written once
+    async fn fill_arcs(
+        &mut self,
+        left: i32,
+        right: i32,
+        top: i32,
+        bottom: i32,
+        r: i32,
+        color: Rgb565,
+    ) -> Result<(), Error<E, PE>> {
+        let (mut x, mut y, mut err) = (r, 0i32, 1 - r);
+
+        while x >= y {
+            // Rows at vertical offset y, half-width x
+            if y > 0 {
+                self.fill_span(left - x, right + x, top - y, color).await?;
+                self.fill_span(left - x, right + x, bottom + y, color)
+                    .await?;
+            }
+
+            y += 1;
+            if err < 0 {
+                err += 2 * y + 1;
+            } else {
+                // x shrinks next, so rows at vertical offset x have reached their final width
+                if x != y - 1 {
+                    self.fill_span(left - (y - 1), right + (y - 1), top - x, color)
+                        .await?;
+                    self.fill_span(left - (y - 1), right + (y - 1), bottom + x, color)
+                        .await?;
+                }
+                x -= 1;
+                err += 2 * (y - x) + 1;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Fill the horizontal span x0..=x1 on row y, clipped to the screen
+    async fn fill_span(
+        &mut self,
+        x0: i32,
+        x1: i32,
+        y: i32,
+        color: Rgb565,
+    ) -> Result<(), Error<E, PE>> {
+        let span = Rectangle::new(Point::new(x0, y), Size::new((x1 - x0 + 1) as u32, 1));
+        self.fill_solid_area(&span, color).await
+    }
+
+    /// Stream colors for a rectangular area, clipped to the screen, through one window write
+    async fn fill_area<I>(&mut self, area: &Rectangle, colors: I) -> Result<(), Error<E, PE>>
+    where
+        I: IntoIterator<Item = Rgb565>,
+    {
+        // Rotated windows are not addressed in logical row-major order, so fall back to row runs
+        #[cfg(feature = "software-rotation")]
+        if self.current_rotation != Rotation::Deg0 {
+            return self
+                .draw_pixels(area.points().zip(colors).map(|(p, c)| Pixel(p, c)))
+                .await;
+        }
+
+        #[cfg(feature = "software-rotation")]
+        let (width, height) = (self.logical_width, self.logical_height);
+        #[cfg(not(feature = "software-rotation"))]
+        let (width, height) = (self.config.width, self.config.height);
+
+        let screen = Rectangle::new(Point::zero(), Size::new(width as u32, height as u32));
+        let drawable = area.intersection(&screen);
+        let Some(bottom_right) = drawable.bottom_right() else {
+            return Ok(()); // Nothing to draw
+        };
+
+        self.set_address_window(
+            drawable.top_left.x as u16,
+            drawable.top_left.y as u16,
+            bottom_right.x as u16,
+            bottom_right.y as u16,
+        )
+        .await?;
+
+        if drawable == *area {
+            // Fully on screen: stream straight from the iterator (e.g. an `ImageRaw`)
+            let total = drawable.size.width as usize * drawable.size.height as usize;
+            self.stream_colors(colors.into_iter().take(total)).await
+        } else {
+            let visible = area
+                .points()
+                .zip(colors)
+                .filter(|(point, _)| drawable.contains(*point))
+                .map(|(_, color)| color);
+            self.stream_colors(visible).await
+        }
+    }
+
+    /// Send colors as pixel data into the current window, batching through the buffer
+    async fn stream_colors<I>(&mut self, colors: I) -> Result<(), Error<E, PE>>
+    where
+        I: Iterator<Item = Rgb565>,
+    {
+        let capacity = self.transfer_pixels();
+        let (mut len, mut written) = (0, 0u32);
+        self.prefilled = None;
+
+        for color in colors {
+            self.buffer.as_mut()[len * 2..len * 2 + 2].copy_from_slice(&color_bytes(color));
+            len += 1;
+
+            if len == capacity {
+                self.write_buffer(len * 2)
+                    .await
+                    .map_err(|e| e.at(written))?;
+                written += len as u32;
+                len = 0;
+            }
+        }
+
+        if len > 0 {
+            self.write_buffer(len * 2)
+                .await
+                .map_err(|e| e.at(written))?;
+        }
+
+        Ok(())
+    }
+
+    /// Clear the whole logical screen to `color`
+    ///
+    /// This is the canonical full-screen wipe: the working buffer is prefilled once and
+    /// pushed repeatedly, and stays prefilled for the next clear with the same color.
+    /// It also resets per-screen state: the remembered progress bar is forgotten, and a
+    /// hardware scroll pointer moved by [`scroll_to`](Self::scroll_to) or
+    /// [`scroll_by`](Self::scroll_by) returns to the top of the scroll area, so the next
+    /// frame is not drawn shifted. The scroll area itself is kept.
+    pub async fn clear(&mut self, color: Rgb565) -> Result<(), Error<E, PE>> {
+        self.fill_screen_with_progress(color, |_, _| {}).await
+    }
+
+    /// Fill entire screen with a single color; same as [`clear`](Self::clear)
+    pub async fn fill_screen(&mut self, color: Rgb565) -> Result<(), Error<E, PE>> {
+        self.clear(color).await
+    }
+
+    /// [`fill_screen`](Self::fill_screen), calling `progress(done_pixels, total_pixels)`
+    /// after each batch
+    ///
+    /// Lets a long fill at low SPI clocks pet a watchdog or advance a boot indicator
+    /// without being split up by the caller. The callback runs between SPI writes, so
+    /// keep it short; batches are sized by [`Config::batch_pixels`]. Resets the scroll
+    /// pointer like [`clear`](Self::clear).
+    pub async fn fill_screen_with_progress<F: FnMut(u32, u32)>(
+        &mut self,
+        color: Rgb565,
+        progress: F,
+    ) -> Result<(), Error<E, PE>> {
+        let (width, height) = self.dimensions();
+
+        // Before init the window below fails with NotInitialized; send nothing else first
+        let top_fixed = self.scroll_area.0;
+        if self.initialized && self.scroll_line != top_fixed {
+            self.scroll_to(top_fixed).await?;
+        }
+        self.set_address_window(0, 0, width - 1, height - 1).await?;
+        self.progress_bar = None;
+
+        let total_pixels = width as u32 * height as u32;
+        self.write_repeated_with_progress(color, total_pixels, progress)
+            .await
+    }
+
+    /// [`fill_screen`](Self::fill_screen) with a raw RGB565 value (e.g. `0xF800` for
+    /// red), for palettes stored as `u16`
+    pub async fn fill_screen_raw(&mut self, color: u16) -> Result<(), Error<E, PE>> {
+        self.clear(Rgb565::from(RawU16::new(color))).await
+    }
+
+    /// [`fill_rect`](Self::fill_rect) with a raw RGB565 value
+    pub async fn fill_rect_raw(
+        &mut self,
+        x: u16,
+        y: u16,
+        width: u16,
+        height: u16,
+        color: u16,
+    ) -> Result<(), Error<E, PE>> {
+        self.fill_rect(x, y, width, height, Rgb565::from(RawU16::new(color)))
+            .await
+    }
+
+    /// Fill a rectangular area with a color (optimized batch implementation)
+    ///
+    /// With `software-rotation`, the rectangle is in logical coordinates and is mapped
+    /// to the matching physical window for every rotation, so adjacent fills (e.g.
+    /// gradient bands) line up. Being a solid fill, the order GRAM is written in
+    /// does not matter.
+    pub async fn fill_rect(
+        &mut self,
+        x: u16,
+        y: u16,
+        width: u16,
+        height: u16,
+        color: Rgb565,
+    ) -> Result<(), Error<E, PE>> {
+        #[cfg(feature = "software-rotation")]
+        let (screen_width, screen_height) = (self.logical_width, self.logical_height);
+        #[cfg(not(feature = "software-rotation"))]
+        let (screen_width, screen_height) = (self.config.width, self.config.height);
+
+        // Bounds checking
+        if x >= screen_width || y >= screen_height {
+            return Ok(()); // Outside screen bounds
+        }
+
+        let actual_width = width.min(screen_width - x);
+        let actual_height = height.min(screen_height - y);
+
+        if actual_width == 0 || actual_height == 0 {
+            return Ok(()); // Nothing to draw
+        }
+
+        self.set_address_window(x, y, x + actual_width - 1, y + actual_height - 1)
+            .await?;
+
+        let total_pixels = actual_width as u32 * actual_height as u32;
+
+        self.write_repeated(color, total_pixels).await
+    }
+
+    /// Fill an embedded-graphics [`Rectangle`] with a color
+    ///
+    /// Signed coordinates are clipped to the screen, so rectangles with a negative
+    /// origin or reaching past an edge fill only their visible part.
+    pub async fn fill_rectangle(
+        &mut self,
+        rect: Rectangle,
+        color: Rgb565,
+    ) -> Result<(), Error<E, PE>> {
+        self.fill_solid_area(&rect, color).await
+    }
+
+    /// Fill a rectangle given by a signed origin, clipped to the screen
+    ///
+    /// For partially visible content, e.g. while scrolling: the part left of or above
+    /// the screen is cut off, and a rectangle entirely off screen is a no-op. Same as
+    /// [`fill_rectangle`](Self::fill_rectangle) without building a [`Rectangle`].
+    pub async fn fill_rect_signed(
+        &mut self,
+        x: i32,
+        y: i32,
+        width: u32,
+        height: u32,
+        color: Rgb565,
+    ) -> Result<(), Error<E, PE>> {
+        #[cfg(feature = "software-rotation")]
+        let (screen_width, screen_height) = (self.logical_width, self.logical_height);
+        #[cfg(not(feature = "software-rotation"))]
+        let (screen_width, screen_height) = (self.config.width, self.config.height);
+
+        // Clip each axis to [0, screen); None if nothing of it is visible
+        let clip = |start: i32, len: u32, screen: u16| {
+            let end = (start as i64 + len as i64).min(screen as i64);
+            let start = (start as i64).max(0);
+            (end > start).then(|| (start as u16, (end - start) as u16))
+        };
+        let (Some((x, width)), Some((y, height))) =
+            (clip(x, width, screen_width), clip(y, height, screen_height))
+        else {
+            return Ok(()); // Entirely off screen
+        };
+
+        self.fill_rect(x, y, width, height, color).await
+    }
+
+    /// Fill a rectangle with per-pixel colors from `f(col, row)`, clipped to the screen
+    ///
+    /// `col` and `row` are relative to (x, y). The window is set once and the colors
+    /// are streamed through the buffer in row-major order, so a vertical gradient is
+    /// just `|_, row| lerp(top, bottom, row)`. `f` is called for clipped pixels too.
+    pub async fn fill_rect_with<F>(
+        &mut self,
+        x: u16,
+        y: u16,
+        width: u16,
+        height: u16,
+        mut f: F,
+    ) -> Result<(), Error<E, PE>>
+    where
+        F: FnMut(u16, u16) -> Rgb565,
+    {
+        let area = Rectangle::new(
+            Point::new(x as i32, y as i32),
+            Size::new(width as u32, height as u32),
+        );
+        let total = width as u32 * height as u32;
+        let colors = (0..total).map(|i| f((i % width as u32) as u16, (i / width as u32) as u16));
+        self.fill_area(&area, colors).await
+    }
+
+    /// Fill the whole logical screen with a [`TestPattern`], in a single window write
+    ///
+    /// Useful to verify wiring, offsets and color order on a new board.
+    pub async fn draw_test_pattern(&mut self, pattern: TestPattern) -> Result<(), Error<E, PE>> {
+        #[cfg(feature = "software-rotation")]
+        let (width, height) = (self.logical_width, self.logical_height);
+        #[cfg(not(feature = "software-rotation"))]
+        let (width, height) = (self.config.width, self.config.height);
+
+        let screen = Rectangle::new(Point::zero(), Size::new(width as u32, height as u32));
+        let colors = screen.points().map(|point| {
+            pattern.color_at(point.x as u32, point.y as u32, width as u32, height as u32)
+        });
+        self.fill_area(&screen, colors).await
+    }
+
+    /// Fill a rectangle with the [`gray`] approximating an 8-bit intensity
+    pub async fn fill_rect_gray(
+        &mut self,
+        x: u16,
+        y: u16,
+        width: u16,
+        height: u16,
+        level: u8,
+    ) -> Result<(), Error<E, PE>> {
+        self.fill_rect(x, y, width, height, gray(level)).await
+    }
+
+    /// Draw a horizontal line of `len` pixels starting at (x, y), clipped to the screen
+    pub async fn draw_hline(
+        &mut self,
+        x: u16,
+        y: u16,
+        len: u16,
+        color: Rgb565,
+    ) -> Result<(), Error<E, PE>> {
+        // A single-row window: the whole line goes out in one burst
+        self.fill_rect(x, y, len, 1, color).await
+    }
+
+    /// Draw a vertical line of `len` pixels starting at (x, y), clipped to the screen
+    pub async fn draw_vline(
         &mut self,
         x: u16,
         y: u16,
-        width: u16,
-        height: u16,
+        len: u16,
+        color: Rgb565,
+    ) -> Result<(), Error<E, PE>> {
+        // A single-column window: the whole line goes out in one burst
+        self.fill_rect(x, y, 1, len, color).await
+    }
+
+    /// Draw a line between two signed endpoints (Bresenham), clipped to the screen
+    ///
+    /// Horizontal and vertical lines become a single window write; other lines merge
+    /// pixels sharing a row into runs.
+    pub async fn draw_line(
+        &mut self,
+        x0: i16,
+        y0: i16,
+        x1: i16,
+        y1: i16,
+        color: Rgb565,
+    ) -> Result<(), Error<E, PE>> {
+        if x0 == x1 || y0 == y1 {
+            let top_left = Point::new(x0.min(x1) as i32, y0.min(y1) as i32);
+            let size = Size::new(x0.abs_diff(x1) as u32 + 1, y0.abs_diff(y1) as u32 + 1);
+            return self
+                .fill_solid_area(&Rectangle::new(top_left, size), color)
+                .await;
+        }
+
+        let start = Point::new(x0 as i32, y0 as i32);
+        let end = Point::new(x1 as i32, y1 as i32);
+        self.draw_pixels(LinePoints::new(start, end).map(|point| Pixel(point, color)))
+            .await
+    }
+
+    /// Draw a triangle outline through three signed vertices, clipped to the screen
+    pub async fn draw_triangle(
+        &mut self,
+        p0: (i16, i16),
+        p1: (i16, i16),
+        p2: (i16, i16),
+        color: Rgb565,
+    ) -> Result<(), Error<E, PE>> {
+        self.draw_line(p0.0, p0.1, p1.0, p1.1, color).await?;
+        self.draw_line(p1.0, p1.1, p2.0, p2.1, color).await?;
+        self.draw_line(p2.0, p2.1, p0.0, p0.1, color).await
+    }
+
+    /// Draw a filled triangle through three signed vertices, clipped to the screen
+    ///
+    /// Scanline fill: each visible row is written once as a horizontal span.
+    pub async fn fill_triangle(
+        &mut self,
+        p0: (i16, i16),
+        p1: (i16, i16),
+        p2: (i16, i16),
         color: Rgb565,
-    ) -> Result<(), Error<E>> {
+    ) -> Result<(), Error<E, PE>> {
+        let mut vertices = [p0, p1, p2].map(|(x, y)| (x as i32, y as i32));
+        vertices.sort_unstable_by_key(|&(_, y)| y);
+        let [a, b, c] = vertices;
+
+        if a.1 == c.1 {
+            // All on one row
+            let (x0, x1) = (a.0.min(b.0).min(c.0), a.0.max(b.0).max(c.0));
+            return self.fill_span(x0, x1, a.1, color).await;
+        }
+
         #[cfg(feature = "software-rotation")]
-        let (screen_width, screen_height) = (self.logical_width, self.logical_height);
+        let height = self.logical_height as i32;
         #[cfg(not(feature = "software-rotation"))]
-        let (screen_width, screen_height) = (self.config.width, self.config.height);
+        let height = self.config.height as i32;
 
-        // Bounds checking
-        if x >= screen_width || y >= screen_height {
-            return Ok(()); // Outside screen bounds
+        // x where the edge p-q crosses row y
+        let edge_x = |p: (i32, i32), q: (i32, i32), y: i32| {
+            if p.1 == q.1 {
+                p.0
+            } else {
+                p.0 + (q.0 - p.0) * (y - p.1) / (q.1 - p.1)
+            }
+        };
+
+        for y in a.1.max(0)..=c.1.min(height - 1) {
+            let long = edge_x(a, c, y);
+            let short = if y < b.1 {
+                edge_x(a, b, y)
+            } else {
+                edge_x(b, c, y)
+            };
+            self.fill_span(long.min(short), long.max(short), y, color)
+                .await?;
         }
 
-        let actual_width = width.min(screen_width - x);
-        let actual_height = height.min(screen_height - y);
+        Ok(())
+    }
 
-        if actual_width == 0 || actual_height == 0 {
+    /// Draw a circle outline of radius `r` centered at (cx, cy), clipped to the screen
+    pub async fn draw_circle(
+        &mut self,
+        cx: u16,
+        cy: u16,
+        r: u16,
+        color: Rgb565,
+    ) -> Result<(), Error<E, PE>> {
+        let (cx, cy) = (cx as i32, cy as i32);
+        self.draw_arcs(cx, cx, cy, cy, r as i32, color).await
+    }
+
+    /// Draw an arc of radius `r` centered at (cx, cy) from `start_deg` clockwise to
+    /// `end_deg`, clipped to the screen
+    ///
+    /// Angles are whole degrees with 0° at 3 o'clock, as in embedded-graphics, so a
+    /// 270° gauge opening at the bottom runs from 135° to 45°. An `end_deg` below
+    /// `start_deg` wraps around; the sweep is at most a full circle. The points at each
+    /// degree are joined by line segments, and `thickness` > 1 adds concentric arcs
+    /// inwards (radii `r`, `r - 1`, ...) for a dial ring.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn draw_arc(
+        &mut self,
+        cx: u16,
+        cy: u16,
+        r: u16,
+        start_deg: i16,
+        end_deg: i16,
+        thickness: u16,
+        color: Rgb565,
+    ) -> Result<(), Error<E, PE>> {
+        let (start, end) = (start_deg as i32, end_deg as i32);
+        let sweep = if end >= start {
+            (end - start).min(360)
+        } else {
+            (end - start).rem_euclid(360)
+        };
+        let (cx, cy) = (cx as i32, cy as i32);
+
+        for radius in (r.saturating_sub(thickness.max(1) - 1)..=r).rev() {
+            let radius = radius as i32;
+            let first = arc_point(cx, cy, radius, start);
+            // Each segment starts where the previous one ended, so skip its first point
+            let segments = (start..start + sweep).flat_map(|deg| {
+                LinePoints::new(
+                    arc_point(cx, cy, radius, deg),
+                    arc_point(cx, cy, radius, deg + 1),
+                )
+                .skip(1)
+            });
+            self.draw_pixels(
+                core::iter::once(first)
+                    .chain(segments)
+                    .map(|point| Pixel(point, color)),
+            )
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Draw a filled circle of radius `r` centered at (cx, cy), clipped to the screen
+    ///
+    /// Each screen row is written once as a horizontal span.
+    pub async fn draw_filled_circle(
+        &mut self,
+        cx: u16,
+        cy: u16,
+        r: u16,
+        color: Rgb565,
+    ) -> Result<(), Error<E, PE>> {
+        let (cx, cy, r) = (cx as i32, cy as i32, r as i32);
+        self.fill_span(cx - r, cx + r, cy, color).await?;
+        self.fill_arcs(cx, cx, cy, cy, r, color).await
+    }
+
+    /// Draw a rounded rectangle outline; `radius` is clamped to `min(width, height) / 2`
+    pub async fn draw_round_rect(
+        &mut self,
+        x: u16,
+        y: u16,
+        width: u16,
+        height: u16,
+        radius: u16,
+        color: Rgb565,
+    ) -> Result<(), Error<E, PE>> {
+        if width == 0 || height == 0 {
             return Ok(()); // Nothing to draw
         }
 
-        self.set_address_window(x, y, x + actual_width - 1, y + actual_height - 1)
+        let r = radius.min(width.min(height) / 2) as i32;
+        let (left, top) = (x as i32 + r, y as i32 + r);
+        let (right, bottom) = (
+            x as i32 + width as i32 - 1 - r,
+            y as i32 + height as i32 - 1 - r,
+        );
+
+        // Straight edges between the corner arcs
+        self.fill_span(left, right, y as i32, color).await?;
+        self.fill_span(left, right, y as i32 + height as i32 - 1, color)
             .await?;
+        let edge_height = (bottom - top + 1) as u32;
+        for edge_x in [x as i32, x as i32 + width as i32 - 1] {
+            let edge = Rectangle::new(Point::new(edge_x, top), Size::new(1, edge_height));
+            self.fill_solid_area(&edge, color).await?;
+        }
 
-        let color_raw = RawU16::from(color).into_inner();
-        let color_bytes = color_raw.to_be_bytes();
+        self.draw_arcs(left, right, top, bottom, r, color).await
+    }
 
-        let total_pixels = actual_width as u32 * actual_height as u32;
+    /// Draw a filled rounded rectangle; `radius` is clamped to `min(width, height) / 2`
+    pub async fn fill_round_rect(
+        &mut self,
+        x: u16,
+        y: u16,
+        width: u16,
+        height: u16,
+        radius: u16,
+        color: Rgb565,
+    ) -> Result<(), Error<E, PE>> {
+        if width == 0 || height == 0 {
+            return Ok(()); // Nothing to draw
+        }
 
-        // Use batch transmission for better performance
-        if total_pixels <= 256 {
-            // Small rectangles: send directly
-            for _ in 0..total_pixels {
-                self.write_raw_data(&color_bytes).await?;
-            }
-        } else {
-            // Large rectangles: use batch transmission
-            const BATCH_SIZE: usize = 256; // Send 256 pixels at a time
-            let mut batch_buffer = [0u8; BATCH_SIZE * 2]; // 2 bytes per pixel
+        let r = radius.min(width.min(height) / 2) as i32;
+        let (left, top) = (x as i32 + r, y as i32 + r);
+        let (right, bottom) = (
+            x as i32 + width as i32 - 1 - r,
+            y as i32 + height as i32 - 1 - r,
+        );
+
+        // Full-width middle band, then the rounded caps above and below it
+        let band = Rectangle::new(
+            Point::new(x as i32, top),
+            Size::new(width as u32, (bottom - top + 1) as u32),
+        );
+        self.fill_solid_area(&band, color).await?;
+        self.fill_arcs(left, right, top, bottom, r, color).await
+    }
 
-            // Fill batch buffer with color
-            for i in 0..BATCH_SIZE {
-                batch_buffer[i * 2] = color_bytes[0];
-                batch_buffer[i * 2 + 1] = color_bytes[1];
-            }
+    /// Draw a progress bar: a 1px `border`, `percent` of the inside in `fg` growing
+    /// from the left, and the rest in `bg`
+    ///
+    /// `percent` is clamped to 100. Calling again with the same position, size and
+    /// colors only redraws the strip between the old and new fill, so an advancing
+    /// bar doesn't flicker; [`clear`](Self::clear) forgets the remembered bar, other
+    /// drawing over it doesn't. Only the most recent bar is remembered. Fails with
+    /// `InvalidArgument` if `width` or `height` is below 3, or if the bar extends past
+    /// coordinate 65535; parts beyond the screen are clipped like any other fill.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn draw_progress_bar(
+        &mut self,
+        x: u16,
+        y: u16,
+        width: u16,
+        height: u16,
+        percent: u8,
+        fg: Rgb565,
+        bg: Rgb565,
+        border: Rgb565,
+    ) -> Result<(), Error<E, PE>> {
+        if width < 3
+            || height < 3
+            || x.checked_add(width).is_none()
+            || y.checked_add(height).is_none()
+        {
+            return Err(Error::InvalidArgument);
+        }
 
-            // Send full batches
-            let full_batches = total_pixels / BATCH_SIZE as u32;
-            for _ in 0..full_batches {
-                self.write_raw_data(&batch_buffer).await?;
+        let (inner_x, inner_y) = (x + 1, y + 1);
+        let (inner_width, inner_height) = (width - 2, height - 2);
+        let filled = (inner_width as u32 * percent.min(100) as u32 / 100) as u16;
+        let bar = ProgressBar {
+            area: (x, y, width, height),
+            colors: (fg, bg, border),
+            filled,
+        };
+
+        match self.progress_bar {
+            Some(last) if ProgressBar { filled, ..last } == bar => {
+                // Same bar: only the strip between the old and new fill changes
+                let (from, to, color) = if filled >= last.filled {
+                    (last.filled, filled, fg)
+                } else {
+                    (filled, last.filled, bg)
+                };
+                if to > from {
+                    self.fill_rect(inner_x + from, inner_y, to - from, inner_height, color)
+                        .await?;
+                }
             }
-
-            // Send remaining pixels
-            let remaining_pixels = (total_pixels % BATCH_SIZE as u32) as usize;
-            if remaining_pixels > 0 {
-                let remaining_bytes = remaining_pixels * 2;
-                self.write_raw_data(&batch_buffer[..remaining_bytes])
+            _ => {
+                self.draw_hline(x, y, width, border).await?;
+                self.draw_hline(x, y + height - 1, width, border).await?;
+                self.draw_vline(x, inner_y, inner_height, border).await?;
+                self.draw_vline(x + width - 1, inner_y, inner_height, border)
                     .await?;
+                self.fill_rect(inner_x, inner_y, filled, inner_height, fg)
+                    .await?;
+                self.fill_rect(
+                    inner_x + filled,
+                    inner_y,
+                    inner_width - filled,
+                    inner_height,
+                    bg,
+                )
+                .await?;
             }
         }
 
+        self.progress_bar = Some(bar);
         Ok(())
     }
 
     /// Sets the global offset of the displayed image
+    ///
+    /// Ends a pending [`set_pixel`](Self::set_pixel) run, which was addressed with the
+    /// old offset.
     pub fn set_offset(&mut self, dx: u16, dy: u16) {
         self.config.dx = dx;
         self.config.dy = dy;
+        self.end_memory_write();
     }
 
     /// Sets the address window for the display with software rotation support
+    /// and starts a Memory Write (2Ch), so pixel data can follow immediately
+    ///
+    /// Fails with `InvalidArgument` if `ex < sx` or `ey < sy`, like `set_window`.
     pub async fn set_address_window(
         &mut self,
         sx: u16,
         sy: u16,
         ex: u16,
         ey: u16,
-    ) -> Result<(), Error<E>> {
+    ) -> Result<(), Error<E, PE>> {
+        self.set_window(sx, sy, ex, ey).await?;
+
+        // Memory write command (0x2C)
+        self.command(Instruction::MemoryWrite, &[]).await
+    }
+
+    /// Set the column (2Ah) and page (2Bh) address range only, without starting a
+    /// write; e.g. before a Memory Read (2Eh)
+    ///
+    /// The end coordinates are inclusive. Fails with `InvalidArgument` before sending
+    /// anything if `ex < sx` or `ey < sy`: the controller would take the backwards
+    /// window without complaint and drop the pixel data. Every drawing and read-back
+    /// call addresses its window here, so before [`init`](Self::init) they all fail
+    /// with `NotInitialized`.
+    pub async fn set_window(
+        &mut self,
+        sx: u16,
+        sy: u16,
+        ex: u16,
+        ey: u16,
+    ) -> Result<(), Error<E, PE>> {
+        if !self.initialized {
+            return Err(Error::NotInitialized);
+        }
+        if ex < sx || ey < sy {
+            return Err(Error::InvalidArgument);
+        }
+
         #[cfg(feature = "software-rotation")]
-        {
-            // Transform logical coordinates to physical coordinates
-            let (phys_sx, phys_sy) = self.transform_coordinates(sx, sy);
-            let (phys_ex, phys_ey) = self.transform_coordinates(ex, ey);
-
-            // Ensure we have the correct min/max values
-            let min_x = phys_sx.min(phys_ex);
-            let max_x = phys_sx.max(phys_ex);
-            let min_y = phys_sy.min(phys_ey);
-            let max_y = phys_sy.max(phys_ey);
-
-            // Apply display offset
-            let sx_offset = min_x + self.config.dx;
-            let sy_offset = min_y + self.config.dy;
-            let ex_offset = max_x + self.config.dx;
-            let ey_offset = max_y + self.config.dy;
-
-            // Column address set (0x2A)
-            self.write_command(
-                0x2A,
-                &[
-                    (sx_offset >> 8) as u8,
-                    (sx_offset & 0xFF) as u8,
-                    (ex_offset >> 8) as u8,
-                    (ex_offset & 0xFF) as u8,
-                ],
-            )
+        let (sx, sy, ex, ey) = {
+            // Rotated by 90°/270°, the logical window maps to a transposed physical one
+            let (x, y, width, height) = self.transform_rect(sx, sy, ex - sx + 1, ey - sy + 1);
+            (x, y, x + width - 1, y + height - 1)
+        };
+
+        // Apply display offset
+        let [sx_hi, sx_lo] = (sx + self.config.dx).to_be_bytes();
+        let [ex_hi, ex_lo] = (ex + self.config.dx).to_be_bytes();
+        let [sy_hi, sy_lo] = (sy + self.config.dy).to_be_bytes();
+        let [ey_hi, ey_lo] = (ey + self.config.dy).to_be_bytes();
+
+        // Column address set (0x2A)
+        self.command(Instruction::ColumnAddressSet, &[sx_hi, sx_lo, ex_hi, ex_lo])
             .await?;
 
-            // Page address set (0x2B)
-            self.write_command(
-                0x2B,
-                &[
-                    (sy_offset >> 8) as u8,
-                    (sy_offset & 0xFF) as u8,
-                    (ey_offset >> 8) as u8,
-                    (ey_offset & 0xFF) as u8,
-                ],
-            )
+        // Page address set (0x2B)
+        self.command(Instruction::PageAddressSet, &[sy_hi, sy_lo, ey_hi, ey_lo])
+            .await
+    }
+
+    /// Fill the entire screen with a color, batching through the working buffer
+    ///
+    /// Equivalent to [`clear`](Self::clear); works with buffers smaller than a row.
+    pub async fn fill_color(&mut self, color: Rgb565) -> Result<(), Error<E, PE>> {
+        self.clear(color).await
+    }
+
+    /// Write a tightly packed RGB565 image into a rectangular area (clipped to the screen)
+    pub async fn write_image(
+        &mut self,
+        x: u16,
+        y: u16,
+        width: u16,
+        height: u16,
+        data: &[u16],
+    ) -> Result<(), Error<E, PE>> {
+        if data.len() != width as usize * height as usize {
+            return Err(Error::InvalidArgument);
+        }
+
+        let area = Rectangle::new(
+            Point::new(x as i32, y as i32),
+            Size::new(width as u32, height as u32),
+        );
+        let colors = data.iter().map(|&raw| Rgb565::from(RawU16::new(raw)));
+        self.fill_area(&area, colors).await
+    }
+
+    /// Stream a `width`x`height` image from `reader` into a window, e.g. from a file on
+    /// an SD card, without holding more than the working buffer in RAM
+    ///
+    /// The reader must yield the pixels as tightly packed big-endian RGB565 (the wire
+    /// format), row by row; its bytes are forwarded as read, so reads of any size work.
+    /// The window must lie within the screen, as for
+    /// [`begin_pixels`](Self::begin_pixels). Fails with [`Error::Read`] if the reader
+    /// fails or ends early; the rest of the window is then left undrawn.
+    ///
+    /// `reader` is an `embedded_io_async::Read` with `async`, `embedded_io::Read`
+    /// without.
+    #[cfg(feature = "embedded-io")]
+    pub async fn write_image_from<R: IoRead>(
+        &mut self,
+        x: u16,
+        y: u16,
+        width: u16,
+        height: u16,
+        reader: &mut R,
+    ) -> Result<(), Error<E, PE>> {
+        use embedded_io::Error as _;
+
+        self.begin_pixels(x, y, width, height).await?;
+
+        let capacity = self.transfer_pixels() * 2;
+        let mut remaining = width as usize * height as usize * 2;
+        self.prefilled = None;
+        // Bytes read into the buffer but not sent yet; at most one, since only whole
+        // pixels are sent
+        let mut pending = 0;
+        while remaining > 0 {
+            let end = capacity.min(remaining);
+            let read = reader
+                .read(&mut self.buffer.as_mut()[pending..end])
+                .await
+                .map_err(|e| Error::Read(e.kind()))?;
+            if read == 0 {
+                return Err(Error::Read(embedded_io::ErrorKind::InvalidData));
+            }
+
+            let len = pending + read;
+            let whole = len & !1;
+            if whole > 0 {
+                self.write_buffer(whole).await?;
+                remaining -= whole;
+                self.window_remaining -= whole as u32 / 2;
+                if len > whole {
+                    self.buffer.as_mut()[0] = self.buffer.as_ref()[whole];
+                }
+            }
+            pending = len - whole;
+        }
+
+        Ok(())
+    }
+
+    /// Copy the dirty rectangle (x, y, width, height) of a full-screen framebuffer to the
+    /// same place on screen (clipped to the screen)
+    ///
+    /// `fb` is row-major with `fb_stride` pixels per row; only the sub-rectangle is sent,
+    /// row by row, through a single address window.
+    pub async fn flush_region(
+        &mut self,
+        fb: &[u16],
+        fb_stride: u16,
+        x: u16,
+        y: u16,
+        width: u16,
+        height: u16,
+    ) -> Result<(), Error<E, PE>> {
+        if width == 0 || height == 0 {
+            return Ok(()); // Nothing to flush
+        }
+
+        let stride = fb_stride as usize;
+        let last_row_end =
+            (y as usize + height as usize - 1) * stride + x as usize + width as usize;
+        if x as usize + width as usize > stride || last_row_end > fb.len() {
+            return Err(Error::InvalidArgument);
+        }
+
+        let area = Rectangle::new(
+            Point::new(x as i32, y as i32),
+            Size::new(width as u32, height as u32),
+        );
+        let colors = (y as usize..y as usize + height as usize).flat_map(|row| {
+            let start = row * stride + x as usize;
+            fb[start..start + width as usize]
+                .iter()
+                .map(|&raw| Rgb565::from(RawU16::new(raw)))
+        });
+        self.fill_area(&area, colors).await
+    }
+
+    /// Send what changed in a [`FrameBuffer`](framebuffer::FrameBuffer) to the screen,
+    /// the frame placed at the top-left corner and clipped to the screen
+    ///
+    /// Only the frame's [`dirty_region`](framebuffer::FrameBuffer::dirty_region) goes
+    /// out, through one address window, and is marked clean once sent; with nothing
+    /// drawn since the last present this sends nothing. Call
+    /// [`mark_all_dirty`](framebuffer::FrameBuffer::mark_all_dirty) first to resend
+    /// the whole frame.
+    #[cfg(feature = "framebuffer")]
+    pub async fn present(
+        &mut self,
+        fb: &mut framebuffer::FrameBuffer<'_>,
+    ) -> Result<(), Error<E, PE>> {
+        let Some((x, y, width, height)) = fb.dirty_region() else {
+            return Ok(()); // Nothing changed
+        };
+
+        self.flush_region(fb.pixels(), fb.width(), x, y, width, height)
             .await?;
+        fb.mark_clean();
+        Ok(())
+    }
+
+    /// Read back a rectangle of GRAM (2Eh) into `out` as RGB565, row-major
+    ///
+    /// The controller returns 3 bytes (6 significant bits per channel) per pixel after
+    /// a dummy byte; they are packed back to 5-6-5, so written colors read back
+    /// unchanged. The rectangle must lie within the screen and `out` must hold exactly
+    /// `width * height` pixels. Reads go through the working buffer in chunks, and
+    /// require the panel's SDO line to be wired to the MCU.
+    pub async fn read_pixels(
+        &mut self,
+        x: u16,
+        y: u16,
+        width: u16,
+        height: u16,
+        out: &mut [u16],
+    ) -> Result<(), Error<E, PE>> {
+        let chunk_pixels = self.read_chunk_pixels(x, y, width, height)?;
+        if out.len() != width as usize * height as usize {
+            return Err(Error::InvalidArgument);
+        }
+
+        self.set_window(x, y, x + width - 1, y + height - 1).await?;
+
+        let mut cmd = Instruction::MemoryRead;
+        for chunk in out.chunks_mut(chunk_pixels) {
+            self.read_gram(cmd, chunk.len()).await?;
+            for (pixel, rgb) in chunk
+                .iter_mut()
+                .zip(self.buffer.as_ref()[1..].chunks_exact(3))
+            {
+                *pixel = rgb666_to_raw565(rgb);
+            }
+            cmd = Instruction::MemoryReadContinue;
+        }
+
+        Ok(())
+    }
+
+    /// Invert the colors of a rectangle in place by reading it back (2Eh), e.g. for a
+    /// blinking cursor or a selection highlight
+    ///
+    /// Same requirements as [`read_pixels`](Self::read_pixels), but no caller storage is
+    /// needed: each row is processed in chunks through the working buffer. Without SDO
+    /// wired, redraw the known colors with [`complement`] instead.
+    pub async fn invert_region(
+        &mut self,
+        x: u16,
+        y: u16,
+        width: u16,
+        height: u16,
+    ) -> Result<(), Error<E, PE>> {
+        let chunk_pixels = self.read_chunk_pixels(x, y, width, height)? as u16;
+
+        for row in y..y + height {
+            let mut col = x;
+            while col < x + width {
+                let len = (x + width - col).min(chunk_pixels);
+                self.set_window(col, row, col + len - 1, row).await?;
+                self.read_gram(Instruction::MemoryRead, len as usize)
+                    .await?;
+
+                // Pack in place: pixel i is read from bytes 1 + 3i before bytes 2i.. are written
+                for i in 0..len as usize {
+                    let rgb = &self.buffer.as_ref()[1 + i * 3..4 + i * 3];
+                    let inverted = !rgb666_to_raw565(rgb);
+                    self.buffer.as_mut()[i * 2..i * 2 + 2].copy_from_slice(&raw_bytes(inverted));
+                }
 
-            // Memory write command (0x2C)
-            self.write_command(0x2C, &[]).await?;
+                self.set_address_window(col, row, col + len - 1, row)
+                    .await?;
+                self.write_buffer(len as usize * 2).await?;
+                col += len;
+            }
         }
 
+        Ok(())
+    }
+
+    /// Validate a read-back rectangle and return how many pixels fit one buffered read
+    fn read_chunk_pixels(
+        &self,
+        x: u16,
+        y: u16,
+        width: u16,
+        height: u16,
+    ) -> Result<usize, Error<E, PE>> {
+        #[cfg(feature = "software-rotation")]
+        let (screen_width, screen_height) = (self.logical_width, self.logical_height);
         #[cfg(not(feature = "software-rotation"))]
+        let (screen_width, screen_height) = (self.config.width, self.config.height);
+
+        let chunk_pixels = self.buffer.as_ref().len().saturating_sub(1) / 3;
+        if width == 0
+            || height == 0
+            || x as u32 + width as u32 > screen_width as u32
+            || y as u32 + height as u32 > screen_height as u32
+            || chunk_pixels == 0
         {
-            // Apply display offset
-            let sx_offset = sx + self.config.dx;
-            let sy_offset = sy + self.config.dy;
-            let ex_offset = ex + self.config.dx;
-            let ey_offset = ey + self.config.dy;
-
-            // Column address set (0x2A)
-            self.write_command(
-                0x2A,
-                &[
-                    (sx_offset >> 8) as u8,
-                    (sx_offset & 0xFF) as u8,
-                    (ex_offset >> 8) as u8,
-                    (ex_offset & 0xFF) as u8,
-                ],
-            )
-            .await?;
+            return Err(Error::InvalidArgument);
+        }
 
-            // Page address set (0x2B)
-            self.write_command(
-                0x2B,
-                &[
-                    (sy_offset >> 8) as u8,
-                    (sy_offset & 0xFF) as u8,
-                    (ey_offset >> 8) as u8,
-                    (ey_offset & 0xFF) as u8,
-                ],
-            )
+        // Rotated windows are not read back in logical row-major order
+        #[cfg(feature = "software-rotation")]
+        if self.current_rotation != Rotation::Deg0 {
+            return Err(Error::InvalidArgument);
+        }
+
+        Ok(chunk_pixels)
+    }
+
+    /// Read `pixels` pixels of GRAM with `cmd` into the working buffer
+    ///
+    /// The buffer receives the dummy byte followed by 3 bytes per pixel.
+    async fn read_gram(&mut self, cmd: Instruction, pixels: usize) -> Result<(), Error<E, PE>> {
+        self.prefilled = None;
+        self.end_memory_write();
+        self.dc.set_low().map_err(Error::Pin)?;
+        let operations = &mut [
+            Operation::Write(&[cmd as u8]),
+            Operation::Read(&mut self.buffer.as_mut()[..1 + pixels * 3]),
+        ];
+        match &mut self.read_spi {
+            Some(read_spi) => read_spi.transaction(operations).await,
+            None => self.spi.transaction(operations).await,
+        }
+        .map_err(Error::Comm)
+    }
+
+    /// Set an address window once, then stream its pixels with
+    /// [`write_pixels`](Self::write_pixels) in chunks of any size
+    ///
+    /// The window must lie within the screen. With `software-rotation`, streaming is
+    /// only supported at `Rotation::Deg0`, since rotated windows are not filled in
+    /// logical row-major order. Any other command, including another drawing call,
+    /// ends the stream.
+    pub async fn begin_pixels(
+        &mut self,
+        x: u16,
+        y: u16,
+        width: u16,
+        height: u16,
+    ) -> Result<(), Error<E, PE>> {
+        #[cfg(feature = "software-rotation")]
+        let (screen_width, screen_height) = (self.logical_width, self.logical_height);
+        #[cfg(not(feature = "software-rotation"))]
+        let (screen_width, screen_height) = (self.config.width, self.config.height);
+
+        if width == 0
+            || height == 0
+            || x as u32 + width as u32 > screen_width as u32
+            || y as u32 + height as u32 > screen_height as u32
+        {
+            return Err(Error::InvalidArgument);
+        }
+
+        #[cfg(feature = "software-rotation")]
+        if self.current_rotation != Rotation::Deg0 {
+            return Err(Error::InvalidArgument);
+        }
+
+        self.set_address_window(x, y, x + width - 1, y + height - 1)
             .await?;
+        self.window_remaining = width as u32 * height as u32;
+        Ok(())
+    }
+
+    /// Write the next run of RGB565 pixels into the window opened by
+    /// [`begin_pixels`](Self::begin_pixels), continuing where the last run ended
+    ///
+    /// Fails with `InvalidArgument` if no stream is open or the run overflows the window.
+    pub async fn write_pixels(&mut self, pixels: &[u16]) -> Result<(), Error<E, PE>> {
+        if pixels.len() as u32 > self.window_remaining {
+            return Err(Error::InvalidArgument);
+        }
 
-            // Memory write command (0x2C)
-            self.write_command(0x2C, &[]).await?;
+        let capacity = self.transfer_pixels();
+        self.prefilled = None;
+        for chunk in pixels.chunks(capacity) {
+            for (dst, raw) in self.buffer.as_mut().chunks_exact_mut(2).zip(chunk) {
+                dst.copy_from_slice(&raw_bytes(*raw));
+            }
+            self.write_buffer(chunk.len() * 2).await?;
+            self.window_remaining -= chunk.len() as u32;
         }
 
         Ok(())
     }
 
-    pub async fn fill_color(&mut self, color: Rgb565) -> Result<(), Error<E>> {
-        self.set_address_window(0, 0, self.config.width - 1, self.config.height - 1)
-            .await?;
-        let color = RawU16::from(color).into_inner();
-        for i in 0..720 {
-            let bytes = color.to_le_bytes(); // 将u16转换为小端字节序的[u8; 2]
-            self.buffer[i * 2 + 1] = bytes[0]; // 存储低字节
-            self.buffer[i * 2] = bytes[1]; // 存储高字节
+    /// Number of pixels still missing to fill the window opened by
+    /// [`begin_pixels`](Self::begin_pixels) (0 once the stream has ended)
+    pub fn pixels_remaining(&self) -> u32 {
+        self.window_remaining
+    }
+
+    /// Set an address window once and return a writer that streams pixels into it
+    ///
+    /// Same as [`begin_pixels`](Self::begin_pixels), with the stream tied to a borrow
+    /// of the display so no other command can interrupt it.
+    #[allow(clippy::type_complexity)]
+    pub async fn open_window(
+        &mut self,
+        x: u16,
+        y: u16,
+        width: u16,
+        height: u16,
+    ) -> Result<WindowWriter<'_, 'b, SPI, DC, RST, TIMER, TE, BUF, RSPI>, Error<E, PE>> {
+        self.begin_pixels(x, y, width, height).await?;
+
+        Ok(WindowWriter { display: self })
+    }
+
+    /// Draw a 1bpp bitmap (MSB first, rows of `width` pixels) with `color` on `bg_color`
+    ///
+    /// Rows are packed back to back; only padding bits inside the final byte may be left
+    /// over, any other length is rejected with [`Error::InvalidArgument`]. The bitmap is
+    /// clipped to the screen, so glyphs may cross its edges.
+    pub async fn write_area(
+        &mut self,
+        x: u16,
+        y: u16,
+        width: u16,
+        data: &[u8],
+        color: Rgb565,
+        bg_color: Rgb565,
+    ) -> Result<(), Error<E, PE>> {
+        if width == 0 {
+            return Err(Error::InvalidArgument);
         }
-        // Memory write command is already sent in set_address_window
-        self.dc.set_high().map_err(Error::Pin)?;
-        for _ in 0..self.config.height / 2 {
-            self.spi
-                .write(&self.buffer[..1440])
-                .await
-                .map_err(Error::Comm)?;
+        if data.is_empty() {
+            return Ok(()); // Nothing to draw
+        }
+
+        let pixels = data.len() * 8;
+        let rows = pixels / width as usize;
+        if rows == 0 || pixels - rows * width as usize >= 8 {
+            return Err(Error::InvalidArgument);
+        }
+
+        let area = Rectangle::new(
+            Point::new(x as i32, y as i32),
+            Size::new(width as u32, rows as u32),
+        );
+        let colors = data
+            .iter()
+            .flat_map(|bits| (0..8).map(move |j| bits & (0x80 >> j) != 0))
+            .take(rows * width as usize)
+            .map(|on| if on { color } else { bg_color });
+        self.fill_area(&area, colors).await
+    }
+
+    /// Draw a 1bpp bitmap of any width with `fg` on `bg`, clipped to the screen
+    ///
+    /// `data` is row-major with `ceil(width / 8)` bytes per row, MSB first; padding bits
+    /// at the end of each row are ignored. Its length must be exactly `height` rows.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn draw_bitmap(
+        &mut self,
+        x: u16,
+        y: u16,
+        width: u16,
+        height: u16,
+        data: &[u8],
+        fg: Rgb565,
+        bg: Rgb565,
+    ) -> Result<(), Error<E, PE>> {
+        let stride = (width as usize).div_ceil(8);
+        if data.len() != stride * height as usize {
+            return Err(Error::InvalidArgument);
+        }
+        if width == 0 || height == 0 {
+            return Ok(()); // Nothing to draw
         }
-        Ok(())
+
+        let area = Rectangle::new(
+            Point::new(x as i32, y as i32),
+            Size::new(width as u32, height as u32),
+        );
+        let colors = data.chunks_exact(stride).flat_map(|row| {
+            (0..width as usize).map(move |i| {
+                if row[i / 8] & (0x80 >> (i % 8)) != 0 {
+                    fg
+                } else {
+                    bg
+                }
+            })
+        });
+        self.fill_area(&area, colors).await
     }
 
-    pub async fn write_area(
+    /// Draw a 1bpp bitmap like [`draw_bitmap`](Self::draw_bitmap), each source pixel
+    /// enlarged to a `scale`×`scale` block, clipped to the screen
+    ///
+    /// E.g. 2x/3x headings from a small font table. `scale` must be at least 1.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn draw_bitmap_scaled(
         &mut self,
         x: u16,
         y: u16,
         width: u16,
+        height: u16,
         data: &[u8],
-        color: Rgb565,
-        bg_color: Rgb565,
-    ) -> Result<(), Error<E>> {
-        let height = MAX_DATA_LEN as u16 / width
-            + if MAX_DATA_LEN as u16 % width > 0 {
-                1
-            } else {
-                0
-            };
+        fg: Rgb565,
+        bg: Rgb565,
+        scale: u8,
+    ) -> Result<(), Error<E, PE>> {
+        let stride = (width as usize).div_ceil(8);
+        if data.len() != stride * height as usize || scale == 0 {
+            return Err(Error::InvalidArgument);
+        }
+        if width == 0 || height == 0 {
+            return Ok(()); // Nothing to draw
+        }
 
-        self.set_address_window(x, y, x + width - 1, y + height - 1)
-            .await?;
-        // Memory write command is already sent in set_address_window
-        self.dc.set_high().map_err(Error::Pin)?;
-        let color = RawU16::from(color).into_inner();
-        let bg_color = RawU16::from(bg_color).into_inner();
-        let front_bytes = color.to_le_bytes();
-        let back_bytes = bg_color.to_le_bytes();
-        for (i, bits) in data.iter().enumerate() {
-            for j in 0..8 {
-                if *bits & (1 << (7 - j)) != 0 {
-                    self.buffer[(i * 8 + j) * 2] = front_bytes[1];
-                    self.buffer[(i * 8 + j) * 2 + 1] = front_bytes[0];
-                } else {
-                    self.buffer[(i * 8 + j) * 2] = back_bytes[1];
-                    self.buffer[(i * 8 + j) * 2 + 1] = back_bytes[0];
-                }
-            }
+        let scale = scale as usize;
+        let area = Rectangle::new(
+            Point::new(x as i32, y as i32),
+            Size::new(
+                (width as usize * scale) as u32,
+                (height as usize * scale) as u32,
+            ),
+        );
+        let colors = data
+            .chunks_exact(stride)
+            .flat_map(|row| core::iter::repeat_n(row, scale))
+            .flat_map(|row| {
+                (0..width as usize * scale).map(move |i| {
+                    let i = i / scale;
+                    if row[i / 8] & (0x80 >> (i % 8)) != 0 {
+                        fg
+                    } else {
+                        bg
+                    }
+                })
+            });
+        self.fill_area(&area, colors).await
+    }
+
+    /// Draw a row of equally sized 1bpp glyphs side by side through one window write,
+    /// clipped to the screen
+    ///
+    /// Each glyph is laid out like [`draw_bitmap`](Self::draw_bitmap) data
+    /// (`ceil(glyph_w / 8)` bytes per row, MSB first). A status line then costs one
+    /// address window instead of one per character. Fails with `InvalidArgument` if a
+    /// glyph's length doesn't match `glyph_w`x`glyph_h`.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn blit_text_row(
+        &mut self,
+        x: u16,
+        y: u16,
+        glyphs: &[&[u8]],
+        glyph_w: u16,
+        glyph_h: u16,
+        fg: Rgb565,
+        bg: Rgb565,
+    ) -> Result<(), Error<E, PE>> {
+        let stride = (glyph_w as usize).div_ceil(8);
+        if glyphs
+            .iter()
+            .any(|glyph| glyph.len() != stride * glyph_h as usize)
+        {
+            return Err(Error::InvalidArgument);
+        }
+        if glyphs.is_empty() || glyph_w == 0 || glyph_h == 0 {
+            return Ok(()); // Nothing to draw
         }
 
-        self.spi
-            .write(&self.buffer[..data.len() * 8 * 2])
-            .await
-            .map_err(Error::Comm)?;
-        Ok(())
+        let area = Rectangle::new(
+            Point::new(x as i32, y as i32),
+            Size::new(glyph_w as u32 * glyphs.len() as u32, glyph_h as u32),
+        );
+        // Row-major over the whole row: every glyph's row r, then row r + 1
+        let colors = (0..glyph_h as usize).flat_map(|row| {
+            glyphs.iter().flat_map(move |glyph| {
+                let bits = &glyph[row * stride..(row + 1) * stride];
+                (0..glyph_w as usize).map(move |i| {
+                    if bits[i / 8] & (0x80 >> (i % 8)) != 0 {
+                        fg
+                    } else {
+                        bg
+                    }
+                })
+            })
+        });
+        self.fill_area(&area, colors).await
     }
 
     #[cfg(feature = "software-rotation")]
     /// Set the current rotation (software rotation feature)
+    ///
+    /// Ends a pending [`set_pixel`](Self::set_pixel) run, which was addressed under the
+    /// old rotation.
     pub fn set_rotation(&mut self, rotation: Rotation) {
         self.current_rotation = rotation;
+        self.end_memory_write();
 
         // Update logical dimensions based on rotation
         match rotation {
@@ -670,17 +3208,117 @@ where
     }
 
     /// Draw a single pixel (basic drawing primitive)
-    pub async fn set_pixel(&mut self, x: u16, y: u16, color: Rgb565) -> Result<(), Error<E>> {
-        if x >= self.config.width || y >= self.config.height {
+    ///
+    /// The window is opened up to the end of the row, so a following `set_pixel` at
+    /// `(x + 1, y)` just continues the Memory Write with 2 data bytes, e.g. when
+    /// plotting a waveform column by column. Any other command ends the run.
+    ///
+    /// With `software-rotation`, coordinates are logical; runs continue at `Deg0` and
+    /// `Deg90`, while `Deg180`/`Deg270` address every pixel on its own.
+    pub async fn set_pixel(&mut self, x: u16, y: u16, color: Rgb565) -> Result<(), Error<E, PE>> {
+        let (width, height) = self.dimensions();
+        if x >= width || y >= height {
             return Ok(()); // Outside bounds
         }
 
-        self.set_address_window(x, y, x, y).await?;
+        // The window goes through the software rotation: at 0° and 90° a logical row is
+        // written in GRAM order, at 180° and 270° it runs backwards and cannot continue
+        #[cfg(feature = "software-rotation")]
+        let row_end = if matches!(self.current_rotation, Rotation::Deg180 | Rotation::Deg270) {
+            x
+        } else {
+            width - 1
+        };
+        #[cfg(not(feature = "software-rotation"))]
+        let row_end = width - 1;
+
+        if self.pixel_cursor != Some((x, y)) {
+            self.set_address_window(x, y, row_end, y).await?;
+        }
+        self.pixel_cursor = None;
+        self.write_repeated(color, 1).await?;
+        if x < row_end {
+            self.pixel_cursor = Some((x + 1, y));
+        }
+        Ok(())
+    }
+
+    /// Draw scattered pixels, merging horizontally adjacent pixels on a row into one window write
+    pub async fn set_pixels_iter(
+        &mut self,
+        pixels: impl IntoIterator<Item = (u16, u16, Rgb565)>,
+    ) -> Result<(), Error<E, PE>> {
+        self.draw_pixels(
+            pixels
+                .into_iter()
+                .map(|(x, y, color)| Pixel(Point::new(x as i32, y as i32), color)),
+        )
+        .await
+    }
+
+    /// Draw ASCII text with the bundled 8x16 font, one window write per glyph
+    ///
+    /// `'\n'` starts a new line at `x`; glyphs are clipped at the screen edges and
+    /// characters outside printable ASCII render as `'?'`.
+    #[cfg(feature = "font-rendering")]
+    pub async fn draw_text(
+        &mut self,
+        x: u16,
+        y: u16,
+        s: &str,
+        fg: Rgb565,
+        bg: Rgb565,
+    ) -> Result<(), Error<E, PE>> {
+        self.draw_glyphs(&font::Ascii8x16, x, y, s, fg, bg).await
+    }
+
+    /// Draw text with any [`GlyphSource`], one window write per glyph
+    ///
+    /// Works without the `font-rendering` feature, so custom fonts don't pull in the
+    /// bundled tables. `'\n'` starts a new line at `x`; characters the font has no
+    /// glyph for leave their cell untouched. Fails with `InvalidArgument` if a glyph
+    /// bitmap does not match the font's size.
+    pub async fn draw_glyphs<G: GlyphSource>(
+        &mut self,
+        font: &G,
+        x: u16,
+        y: u16,
+        s: &str,
+        fg: Rgb565,
+        bg: Rgb565,
+    ) -> Result<(), Error<E, PE>> {
+        let (width, height) = font.size();
+        let stride = (width as usize).div_ceil(8);
+        let glyph_size = Size::new(width as u32, height as u32);
+        let mut cursor = Point::new(x as i32, y as i32);
+
+        for c in s.chars() {
+            if c == '\n' {
+                cursor = Point::new(x as i32, cursor.y + height as i32);
+                continue;
+            }
+
+            if let Some(glyph) = font.glyph(c) {
+                if glyph.len() != stride * height as usize {
+                    return Err(Error::InvalidArgument);
+                }
+                let colors = glyph.chunks_exact(stride).flat_map(|row| {
+                    (0..width as usize).map(move |i| {
+                        if row[i / 8] & (0x80 >> (i % 8)) != 0 {
+                            fg
+                        } else {
+                            bg
+                        }
+                    })
+                });
+                self.fill_area(&Rectangle::new(cursor, glyph_size), colors)
+                    .await?;
+            }
 
-        let color_raw = RawU16::from(color).into_inner();
-        let color_bytes = color_raw.to_be_bytes();
+            cursor.x += width as i32;
+        }
 
-        self.write_raw_data(&color_bytes).await
+        Ok(())
     }
 
     /// Draw a simple 12px digit (0-9) for angle display
@@ -691,12 +3329,12 @@ where
         y: u16,
         digit: u8,
         color: Rgb565,
-    ) -> Result<(), Error<E>> {
-        if digit > 9 {
+    ) -> Result<(), Error<E, PE>> {
+        let Some(font_data) =
+            char::from_digit(digit as u32, 10).and_then(|c| font::Digits12x16.glyph(c))
+        else {
             return Ok(()); // Invalid digit
-        }
-
-        let font_data = get_digit_font_data(digit);
+        };
 
         // Draw 12x16 character
         for row in 0..16 {
@@ -724,7 +3362,7 @@ where
         y: u16,
         angle: u16,
         color: Rgb565,
-    ) -> Result<(), Error<E>> {
+    ) -> Result<(), Error<E, PE>> {
         let mut current_x = x;
 
         // Draw digits
@@ -752,7 +3390,12 @@ where
 
     /// Draw degree symbol (°)
     #[cfg(feature = "font-rendering")]
-    async fn draw_degree_symbol(&mut self, x: u16, y: u16, color: Rgb565) -> Result<(), Error<E>> {
+    async fn draw_degree_symbol(
+        &mut self,
+        x: u16,
+        y: u16,
+        color: Rgb565,
+    ) -> Result<(), Error<E, PE>> {
         // Draw a small 4x4 circle for degree symbol
         let circle_pixels = [
             (1, 0),
@@ -773,70 +3416,541 @@ where
     }
 }
 
+#[cfg(feature = "async")]
+impl<SPI, DC, RST, E, PE, TIMER, TE, BUF, RSPI> GC9307C<'_, SPI, DC, RST, TIMER, TE, BUF, RSPI>
+where
+    SPI: SpiDevice<Error = E>,
+    DC: OutputPin<Error = PE>,
+    RST: OutputPin<Error = PE>,
+    TIMER: Timer,
+    TE: embedded_hal_async::digital::Wait<Error = PE>,
+{
+    /// Wait for the next rising edge on the TE pin (start of vertical blanking)
+    pub async fn wait_for_vsync(&mut self) -> Result<(), Error<E, PE>> {
+        self.te.wait_for_rising_edge().await.map_err(Error::Pin)
+    }
+}
+
+#[cfg(not(feature = "async"))]
+impl<SPI, DC, RST, E, PE, TIMER, TE, BUF, RSPI> GC9307C<'_, SPI, DC, RST, TIMER, TE, BUF, RSPI>
+where
+    SPI: SpiDevice<Error = E>,
+    DC: OutputPin<Error = PE>,
+    RST: OutputPin<Error = PE>,
+    TIMER: Timer,
+    TE: embedded_hal::digital::InputPin<Error = PE>,
+{
+    /// Busy-wait for the next rising edge on the TE pin (start of vertical blanking)
+    pub fn wait_for_vsync(&mut self) -> Result<(), Error<E, PE>> {
+        while self.te.is_high().map_err(Error::Pin)? {}
+        while self.te.is_low().map_err(Error::Pin)? {}
+        Ok(())
+    }
+}
+
+/// Streams pixels into an address window opened by [`GC9307C::open_window`]
+pub struct WindowWriter<'a, 'b, SPI, DC, RST, TIMER, TE = NoPin, BUF = &'b mut [u8], RSPI = SPI>
+where
+    SPI: SpiDevice,
+    DC: OutputPin,
+    RST: OutputPin,
+    TIMER: Timer,
+{
+    display: &'a mut GC9307C<'b, SPI, DC, RST, TIMER, TE, BUF, RSPI>,
+}
+
+#[maybe_async_cfg::maybe(
+    sync(cfg(not(feature = "async")), self = "WindowWriter",),
+    async(feature = "async", keep_self)
+)]
+impl<SPI, DC, RST, E, PE, TIMER, TE, BUF, RSPI>
+    WindowWriter<'_, '_, SPI, DC, RST, TIMER, TE, BUF, RSPI>
+where
+    SPI: SpiDevice<Error = E>,
+    DC: OutputPin<Error = PE>,
+    RST: OutputPin<Error = PE>,
+    TIMER: Timer,
+    BUF: AsRef<[u8]> + AsMut<[u8]>,
+    RSPI: SpiDevice<Error = E>,
+{
+    /// Write the next run of RGB565 pixels (row-major, continuing where the last run ended)
+    pub async fn push(&mut self, pixels: &[u16]) -> Result<(), Error<E, PE>> {
+        self.display.write_pixels(pixels).await
+    }
+
+    /// Number of pixels still missing to fill the window
+    pub fn remaining(&self) -> u32 {
+        self.display.window_remaining
+    }
+
+    /// Finish streaming, failing if the window was not completely filled
+    pub fn finish(self) -> Result<(), Error<E, PE>> {
+        if self.display.window_remaining > 0 {
+            return Err(Error::InvalidArgument);
+        }
+        Ok(())
+    }
+}
+
+impl<SPI, DC, RST, E, PE, TIMER, TE, BUF, RSPI> OriginDimensions
+    for GC9307C<'_, SPI, DC, RST, TIMER, TE, BUF, RSPI>
+where
+    SPI: SpiDevice<Error = E>,
+    DC: OutputPin<Error = PE>,
+    RST: OutputPin<Error = PE>,
+    TIMER: Timer,
+    BUF: AsRef<[u8]> + AsMut<[u8]>,
+    RSPI: SpiDevice<Error = E>,
+{
+    /// Logical screen size (after software rotation, if enabled)
+    fn size(&self) -> Size {
+        self.bounding_box().size
+    }
+}
+
+impl<SPI, DC, RST, E, PE, TIMER, TE, BUF, RSPI> DrawTarget
+    for GC9307C<'_, SPI, DC, RST, TIMER, TE, BUF, RSPI>
+where
+    SPI: SpiDevice<Error = E>,
+    DC: OutputPin<Error = PE>,
+    RST: OutputPin<Error = PE>,
+    TIMER: Timer,
+    BUF: AsRef<[u8]> + AsMut<[u8]>,
+    RSPI: SpiDevice<Error = E>,
+{
+    type Color = Rgb565;
+    type Error = Error<E, PE>;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        block_on(self.draw_pixels(pixels))
+    }
+
+    fn fill_contiguous<I>(&mut self, area: &Rectangle, colors: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Self::Color>,
+    {
+        block_on(self.fill_area(area, colors))
+    }
+
+    fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+        block_on(self.fill_solid_area(area, color))
+    }
+}
+
+/// Text console on top of the bundled 8x16 font, for `write!` straight to the screen
+///
+/// Text wraps at the right edge. At the bottom the console scrolls up one line in
+/// hardware (Vertical Scroll Start Address, 37h) when the display is in
+/// [`Orientation::Portrait`] over the full 320-line GRAM; in other orientations it
+/// wraps to the top, clearing each line before it is reused.
+///
+/// `core::fmt::Write` is a blocking trait, so with the `async` feature writes busy-poll
+/// the SPI device like the `DrawTarget` impl does.
+#[cfg(feature = "font-rendering")]
+pub struct TextConsole<'a, 'b, SPI, DC, RST, TIMER, TE = NoPin, BUF = &'b mut [u8], RSPI = SPI>
+where
+    SPI: SpiDevice,
+    DC: OutputPin,
+    RST: OutputPin,
+    TIMER: Timer,
+{
+    display: &'a mut GC9307C<'b, SPI, DC, RST, TIMER, TE, BUF, RSPI>,
+    fg: Rgb565,
+    bg: Rgb565,
+    /// Cursor position in text cells, `row` counted from the top of the screen
+    col: u16,
+    row: u16,
+    /// Text line of GRAM shown at the top of the screen while hardware scrolling
+    first: u16,
+}
+
+#[cfg(feature = "font-rendering")]
+impl<'a, 'b, SPI, DC, RST, TIMER, TE, BUF, RSPI>
+    TextConsole<'a, 'b, SPI, DC, RST, TIMER, TE, BUF, RSPI>
+where
+    SPI: SpiDevice,
+    DC: OutputPin,
+    RST: OutputPin,
+    TIMER: Timer,
+{
+    /// Create a console with its cursor at the top-left corner
+    ///
+    /// Call [`clear`](Self::clear) first unless the screen is already blank and unscrolled.
+    pub fn new(
+        display: &'a mut GC9307C<'b, SPI, DC, RST, TIMER, TE, BUF, RSPI>,
+        fg: Rgb565,
+        bg: Rgb565,
+    ) -> Self {
+        Self {
+            display,
+            fg,
+            bg,
+            col: 0,
+            row: 0,
+            first: 0,
+        }
+    }
+
+    /// Change the colors used for subsequent text
+    pub fn set_colors(&mut self, fg: Rgb565, bg: Rgb565) {
+        self.fg = fg;
+        self.bg = bg;
+    }
+}
+
+#[cfg(feature = "font-rendering")]
+#[maybe_async_cfg::maybe(
+    sync(cfg(not(feature = "async")), self = "TextConsole",),
+    async(feature = "async", keep_self)
+)]
+impl<SPI, DC, RST, E, PE, TIMER, TE, BUF, RSPI>
+    TextConsole<'_, '_, SPI, DC, RST, TIMER, TE, BUF, RSPI>
+where
+    SPI: SpiDevice<Error = E>,
+    DC: OutputPin<Error = PE>,
+    RST: OutputPin<Error = PE>,
+    TIMER: Timer,
+    BUF: AsRef<[u8]> + AsMut<[u8]>,
+    RSPI: SpiDevice<Error = E>,
+{
+    /// Clear the screen to the background color and move the cursor home
+    pub async fn clear(&mut self) -> Result<(), Error<E, PE>> {
+        self.display.clear(self.bg).await?;
+        if self.first != 0 {
+            self.display.scroll_to(0).await?;
+        }
+        (self.col, self.row, self.first) = (0, 0, 0);
+        Ok(())
+    }
+
+    /// Print `s` at the cursor; `'\n'` starts a new line and `'\r'` returns to its start
+    pub async fn print(&mut self, s: &str) -> Result<(), Error<E, PE>> {
+        let (columns, rows) = self.cells();
+        if columns == 0 || rows == 0 {
+            return Err(Error::InvalidArgument);
+        }
+
+        for c in s.chars() {
+            match c {
+                '\n' => self.newline().await?,
+                '\r' => self.col = 0,
+                _ => {
+                    if self.col == columns {
+                        self.newline().await?;
+                    }
+                    let mut utf8 = [0; 4];
+                    let y = self.line() * font::FONT_HEIGHT;
+                    self.display
+                        .draw_text(
+                            self.col * font::FONT_WIDTH,
+                            y,
+                            c.encode_utf8(&mut utf8),
+                            self.fg,
+                            self.bg,
+                        )
+                        .await?;
+                    self.col += 1;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn newline(&mut self) -> Result<(), Error<E, PE>> {
+        let (_, rows) = self.cells();
+        self.col = 0;
+        if self.row + 1 < rows {
+            self.row += 1;
+            return Ok(());
+        }
+
+        if self.hardware_scroll() {
+            if self.first == 0 {
+                self.display.set_scroll_area(0, MAX_ROW_END, 0).await?;
+            }
+            // The line scrolled out at the top comes back in at the bottom
+            self.first = (self.first + 1) % rows;
+            self.clear_line().await?;
+            self.display.scroll_to(self.first * font::FONT_HEIGHT).await
+        } else {
+            self.row = 0;
+            self.clear_line().await
+        }
+    }
+
+    /// Blank the cursor's line
+    async fn clear_line(&mut self) -> Result<(), Error<E, PE>> {
+        let (width, _) = self.screen_size();
+        let y = self.line() * font::FONT_HEIGHT;
+        self.display
+            .fill_rect(0, y, width, font::FONT_HEIGHT, self.bg)
+            .await
+    }
+
+    /// Text line in drawing coordinates the cursor row currently maps to
+    fn line(&self) -> u16 {
+        let (_, rows) = self.cells();
+        (self.first + self.row) % rows
+    }
+
+    /// Columns and rows of text that fit the screen
+    fn cells(&self) -> (u16, u16) {
+        let (width, height) = self.screen_size();
+        (width / font::FONT_WIDTH, height / font::FONT_HEIGHT)
+    }
+
+    fn screen_size(&self) -> (u16, u16) {
+        #[cfg(feature = "software-rotation")]
+        let size = (self.display.logical_width, self.display.logical_height);
+        #[cfg(not(feature = "software-rotation"))]
+        let size = (self.display.config.width, self.display.config.height);
+        size
+    }
+
+    /// Whether screen rows map 1:1 onto the full GRAM, so 37h scrolls the text
+    fn hardware_scroll(&self) -> bool {
+        #[cfg(feature = "software-rotation")]
+        if self.display.current_rotation != Rotation::Deg0 {
+            return false;
+        }
+
+        let config = &self.display.config;
+        config.orientation as u8 == Orientation::Portrait as u8
+            && !self.display.axes_exchanged
+            && config.height == MAX_ROW_END
+            && config.dy == 0
+    }
+}
+
 #[cfg(feature = "font-rendering")]
-/// Get font data for digits 0-9 (12x16 bitmap)
-fn get_digit_font_data(digit: u8) -> &'static [u8] {
-    match digit {
-        0 => &[
-            0x3F, 0xC0, 0x7F, 0xE0, 0xE0, 0x70, 0xC0, 0x30, 0xC0, 0x30, 0xC0, 0x30, 0xC0, 0x30,
-            0xC0, 0x30, 0xC0, 0x30, 0xC0, 0x30, 0xC0, 0x30, 0xC0, 0x30, 0xE0, 0x70, 0x7F, 0xE0,
-            0x3F, 0xC0, 0x00, 0x00,
-        ],
-        1 => &[
-            0x0C, 0x00, 0x1C, 0x00, 0x3C, 0x00, 0x0C, 0x00, 0x0C, 0x00, 0x0C, 0x00, 0x0C, 0x00,
-            0x0C, 0x00, 0x0C, 0x00, 0x0C, 0x00, 0x0C, 0x00, 0x0C, 0x00, 0x0C, 0x00, 0x3F, 0x00,
-            0x3F, 0x00, 0x00, 0x00,
-        ],
-        2 => &[
-            0x3F, 0xC0, 0x7F, 0xE0, 0xE0, 0x70, 0x00, 0x30, 0x00, 0x30, 0x00, 0x70, 0x00, 0xE0,
-            0x01, 0xC0, 0x03, 0x80, 0x07, 0x00, 0x0E, 0x00, 0x1C, 0x00, 0x38, 0x00, 0x7F, 0xF0,
-            0xFF, 0xF0, 0x00, 0x00,
-        ],
-        3 => &[
-            0x3F, 0xC0, 0x7F, 0xE0, 0xE0, 0x70, 0x00, 0x30, 0x00, 0x30, 0x00, 0x70, 0x0F, 0xE0,
-            0x0F, 0xE0, 0x00, 0x70, 0x00, 0x30, 0x00, 0x30, 0xE0, 0x70, 0x7F, 0xE0, 0x3F, 0xC0,
-            0x00, 0x00, 0x00, 0x00,
-        ],
-        4 => &[
-            0x01, 0xC0, 0x03, 0xC0, 0x07, 0xC0, 0x0D, 0xC0, 0x19, 0xC0, 0x31, 0xC0, 0x61, 0xC0,
-            0xC1, 0xC0, 0xFF, 0xF0, 0xFF, 0xF0, 0x01, 0xC0, 0x01, 0xC0, 0x01, 0xC0, 0x01, 0xC0,
-            0x01, 0xC0, 0x00, 0x00,
-        ],
-        5 => &[
-            0xFF, 0xF0, 0xFF, 0xF0, 0xE0, 0x00, 0xE0, 0x00, 0xE0, 0x00, 0xE0, 0x00, 0xFF, 0xC0,
-            0xFF, 0xE0, 0x00, 0x70, 0x00, 0x30, 0x00, 0x30, 0xE0, 0x70, 0x7F, 0xE0, 0x3F, 0xC0,
-            0x00, 0x00, 0x00, 0x00,
-        ],
-        6 => &[
-            0x1F, 0xC0, 0x3F, 0xE0, 0x70, 0x70, 0xE0, 0x00, 0xE0, 0x00, 0xE0, 0x00, 0xFF, 0xC0,
-            0xFF, 0xE0, 0xE0, 0x70, 0xE0, 0x30, 0xE0, 0x30, 0x70, 0x70, 0x7F, 0xE0, 0x3F, 0xC0,
-            0x00, 0x00, 0x00, 0x00,
-        ],
-        7 => &[
-            0xFF, 0xF0, 0xFF, 0xF0, 0x00, 0x30, 0x00, 0x60, 0x00, 0xC0, 0x01, 0x80, 0x03, 0x00,
-            0x06, 0x00, 0x0C, 0x00, 0x18, 0x00, 0x30, 0x00, 0x60, 0x00, 0xC0, 0x00, 0xC0, 0x00,
-            0xC0, 0x00, 0x00, 0x00,
-        ],
-        8 => &[
-            0x3F, 0xC0, 0x7F, 0xE0, 0xE0, 0x70, 0xE0, 0x70, 0xE0, 0x70, 0x70, 0xE0, 0x3F, 0xC0,
-            0x7F, 0xE0, 0xE0, 0x70, 0xE0, 0x70, 0xE0, 0x70, 0xE0, 0x70, 0x7F, 0xE0, 0x3F, 0xC0,
-            0x00, 0x00, 0x00, 0x00,
-        ],
-        9 => &[
-            0x3F, 0xC0, 0x7F, 0xE0, 0xE0, 0x70, 0xC0, 0x30, 0xC0, 0x30, 0xE0, 0x70, 0x7F, 0xF0,
-            0x3F, 0xF0, 0x00, 0x70, 0x00, 0x70, 0x00, 0x70, 0xE0, 0xE0, 0x7F, 0xC0, 0x3F, 0x80,
-            0x00, 0x00, 0x00, 0x00,
-        ],
-        _ => &[0; 32], // Empty for invalid digits
+impl<SPI, DC, RST, E, PE, TIMER, TE, BUF, RSPI> core::fmt::Write
+    for TextConsole<'_, '_, SPI, DC, RST, TIMER, TE, BUF, RSPI>
+where
+    SPI: SpiDevice<Error = E>,
+    DC: OutputPin<Error = PE>,
+    RST: OutputPin<Error = PE>,
+    TIMER: Timer,
+    BUF: AsRef<[u8]> + AsMut<[u8]>,
+    RSPI: SpiDevice<Error = E>,
+{
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        block_on(self.print(s)).map_err(|_| core::fmt::Error)
+    }
+}
+
+/// sin(0°..=90°) in Q14 fixed point (16384 = 1.0)
+#[rustfmt::skip]
+const SIN_Q14: [i32; 91] = [
+    0, 286, 572, 857, 1143, 1428, 1713, 1997, 2280, 2563,
+    2845, 3126, 3406, 3686, 3964, 4240, 4516, 4790, 5063, 5334,
+    5604, 5872, 6138, 6402, 6664, 6924, 7182, 7438, 7692, 7943,
+    8192, 8438, 8682, 8923, 9162, 9397, 9630, 9860, 10087, 10311,
+    10531, 10749, 10963, 11174, 11381, 11585, 11786, 11982, 12176, 12365,
+    12551, 12733, 12911, 13085, 13255, 13421, 13583, 13741, 13894, 14044,
+    14189, 14330, 14466, 14598, 14726, 14849, 14968, 15082, 15191, 15296,
+    15396, 15491, 15582, 15668, 15749, 15826, 15897, 15964, 16026, 16083,
+    16135, 16182, 16225, 16262, 16294, 16322, 16344, 16362, 16374, 16382,
+    16384,
+];
+
+/// sin(`deg`) in Q14 fixed point, for any whole-degree angle
+fn sin_q14(deg: i32) -> i32 {
+    let deg = deg.rem_euclid(360);
+    match deg {
+        0..=90 => SIN_Q14[deg as usize],
+        91..=180 => SIN_Q14[(180 - deg) as usize],
+        181..=270 => -SIN_Q14[(deg - 180) as usize],
+        _ => -SIN_Q14[(360 - deg) as usize],
+    }
+}
+
+/// Point at `deg` (0° = 3 o'clock, clockwise) on the circle of radius `r` around (cx, cy)
+fn arc_point(cx: i32, cy: i32, r: i32, deg: i32) -> Point {
+    // Round the Q14 products to the nearest pixel
+    let x = (r * sin_q14(deg + 90) + (1 << 13)) >> 14;
+    let y = (r * sin_q14(deg) + (1 << 13)) >> 14;
+    Point::new(cx + x, cy + y)
+}
+
+/// Points of a line between two endpoints (inclusive), using Bresenham's algorithm
+struct LinePoints {
+    point: Point,
+    end: Point,
+    dx: i32,
+    dy: i32,
+    step: Point,
+    err: i32,
+    done: bool,
+}
+
+impl LinePoints {
+    fn new(start: Point, end: Point) -> Self {
+        let dx = (end.x - start.x).abs();
+        let dy = -(end.y - start.y).abs();
+        Self {
+            point: start,
+            end,
+            dx,
+            dy,
+            step: Point::new((end.x - start.x).signum(), (end.y - start.y).signum()),
+            err: dx + dy,
+            done: false,
+        }
+    }
+}
+
+impl Iterator for LinePoints {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Point> {
+        if self.done {
+            return None;
+        }
+
+        let point = self.point;
+        if point == self.end {
+            self.done = true;
+            return Some(point);
+        }
+
+        let e2 = 2 * self.err;
+        if e2 >= self.dy {
+            self.err += self.dy;
+            self.point.x += self.step.x;
+        }
+        if e2 <= self.dx {
+            self.err += self.dx;
+            self.point.y += self.step.y;
+        }
+
+        Some(point)
+    }
+}
+
+/// Geometry, colors and fill width of a bar drawn by `draw_progress_bar`
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct ProgressBar {
+    area: (u16, u16, u16, u16),
+    colors: (Rgb565, Rgb565, Rgb565),
+    filled: u16,
+}
+
+/// Gray with R = G = B approximating an 8-bit intensity (5 bits red/blue, 6 bits green)
+pub fn gray(level: u8) -> Rgb565 {
+    Rgb565::new(level >> 3, level >> 2, level >> 3)
+}
+
+/// Bitwise complement of a color, e.g. to highlight known content without read-back
+pub fn complement(color: Rgb565) -> Rgb565 {
+    Rgb565::from(RawU16::new(!RawU16::from(color).into_inner()))
+}
+
+/// Pack a 3-byte Memory Read pixel (6 significant bits per channel) into raw RGB565
+fn rgb666_to_raw565(rgb: &[u8]) -> u16 {
+    (rgb[0] as u16 >> 3) << 11 | (rgb[1] as u16 >> 2) << 5 | rgb[2] as u16 >> 3
+}
+
+/// Encode a color in the byte order expected by Memory Write
+fn color_bytes(color: Rgb565) -> [u8; 2] {
+    raw_bytes(RawU16::from(color).into_inner())
+}
+
+/// Encode a raw RGB565 value in the byte order expected by Memory Write (big-endian)
+///
+/// All pixel data is packed here, so a panel with another byte order is a one-line change.
+fn raw_bytes(raw: u16) -> [u8; 2] {
+    raw.to_be_bytes()
+}
+
+#[cfg(feature = "async")]
+/// Drive a driver future to completion from a synchronous context.
+///
+/// `DrawTarget` is a blocking trait, so in async builds its methods busy-poll the
+/// underlying async SPI operations. This requires the SPI future to make progress
+/// without the executor running other tasks, which holds for DMA/interrupt driven
+/// HALs on an uncontended bus.
+fn block_on<F: core::future::Future>(future: F) -> F::Output {
+    let mut future = core::pin::pin!(future);
+    let mut cx = core::task::Context::from_waker(core::task::Waker::noop());
+    loop {
+        if let core::task::Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+            return output;
+        }
     }
 }
 
+#[cfg(not(feature = "async"))]
+/// Blocking builds already complete every operation synchronously.
+fn block_on<T>(result: T) -> T {
+    result
+}
+
+#[cfg(feature = "async")]
+/// Let the executor run other tasks once before continuing
+async fn yield_now() {
+    let mut yielded = false;
+    core::future::poll_fn(|cx| {
+        if yielded {
+            core::task::Poll::Ready(())
+        } else {
+            yielded = true;
+            cx.waker().wake_by_ref();
+            core::task::Poll::Pending
+        }
+    })
+    .await
+}
+
+#[cfg(not(feature = "async"))]
+/// Blocking builds have no executor to yield to.
+fn yield_now() {}
+
 #[maybe_async_cfg::maybe(
     sync(cfg(not(feature = "async")), self = "Timer",),
     async(feature = "async", keep_self)
 )]
 /// Simplified timer trait for delay operations.
+#[allow(async_fn_in_trait)]
 pub trait Timer {
     /// Delay for the specified number of milliseconds.
     async fn delay_ms(milliseconds: u64);
 }
+
+/// [`Timer`] adapter for an embedded-hal `DelayNs` (`embedded_hal_async::delay::DelayNs`
+/// with `async`, `embedded_hal::delay::DelayNs` without)
+///
+/// `Timer` has no receiver, so a fresh `D::default()` is created for each delay; this
+/// suits zero-sized delay handles, e.g. `GC9307C::<_, _, _, DelayTimer<MyDelay>>`.
+pub struct DelayTimer<D>(core::marker::PhantomData<D>);
+
+#[maybe_async_cfg::maybe(
+    sync(cfg(not(feature = "async")), self = "DelayTimer",),
+    async(feature = "async", keep_self)
+)]
+impl<D: DelayNs + Default> Timer for DelayTimer<D> {
+    async fn delay_ms(milliseconds: u64) {
+        let mut delay = D::default();
+        // DelayNs takes u32 milliseconds; longer delays are split
+        let mut remaining = milliseconds;
+        while remaining > 0 {
+            let step = remaining.min(u32::MAX as u64);
+            delay.delay_ms(step as u32).await;
+            remaining -= step;
+        }
+    }
+}
+
+/// [`Timer`] backed by `embassy_time::Timer`
+#[cfg(all(feature = "embassy-time", feature = "async"))]
+pub struct EmbassyTimer;
+
+#[cfg(all(feature = "embassy-time", feature = "async"))]
+impl Timer for EmbassyTimer {
+    async fn delay_ms(milliseconds: u64) {
+        embassy_time::Timer::after_millis(milliseconds).await
+    }
+}
@@ -0,0 +1,168 @@
+//! 9-bit (3-wire) SPI for modules without a D/CX line
+//!
+//! In 3-wire mode the GC9307 takes D/CX as the first bit of every 9-bit word instead
+//! of from a GPIO. [`split`] turns an `SpiDevice<u16>` that sends 9-bit frames into
+//! the byte-wide SPI device and DC pin the driver expects: the pin only records the
+//! level, and the SPI wrapper packs it into bit 8 of each word it sends.
+//!
+//! ```ignore
+//! let dc = Cell::new(false);
+//! let (spi, dc_pin) = nine_bit::split(spi_9bit, &dc);
+//! let mut display = GC9307C::<_, _, _, MyTimer>::new(config, spi, dc_pin, rst, buffer);
+//! ```
+//!
+//! Bytes are converted in small chunks, so a long write becomes several transactions;
+//! the controller keeps its state while CS is high between them. Reads (ID, status,
+//! GRAM read-back) use a different 3-wire protocol and fail with
+//! [`NineBitError::ReadUnsupported`] before any byte of their transaction is sent.
+
+use core::cell::Cell;
+use core::convert::Infallible;
+
+use embedded_hal::digital::{ErrorType as PinErrorType, OutputPin};
+use embedded_hal::spi::{self, ErrorType, Operation};
+
+/// Words converted per inner SPI write
+const CHUNK_WORDS: usize = 32;
+
+/// Wrap `spi` for 9-bit D/CX encoding; `dc` holds the level between the two halves
+pub fn split<S>(spi: S, dc: &Cell<bool>) -> (NineBitSpi<'_, S>, NineBitDc<'_>) {
+    (NineBitSpi { spi, dc }, NineBitDc(dc))
+}
+
+/// Error of a [`NineBitSpi`] transaction
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum NineBitError<E> {
+    /// Error of the underlying 9-bit SPI device
+    Spi(E),
+    /// The transaction contained a read, which 3-wire mode does not support here
+    ReadUnsupported,
+}
+
+impl<E: spi::Error> spi::Error for NineBitError<E> {
+    fn kind(&self) -> spi::ErrorKind {
+        match self {
+            NineBitError::Spi(e) => e.kind(),
+            NineBitError::ReadUnsupported => spi::ErrorKind::Other,
+        }
+    }
+}
+
+/// D/CX "pin" of a 9-bit bus: records the level for the next words sent
+pub struct NineBitDc<'a>(&'a Cell<bool>);
+
+impl PinErrorType for NineBitDc<'_> {
+    type Error = Infallible;
+}
+
+impl OutputPin for NineBitDc<'_> {
+    fn set_low(&mut self) -> Result<(), Infallible> {
+        self.0.set(false);
+        Ok(())
+    }
+
+    fn set_high(&mut self) -> Result<(), Infallible> {
+        self.0.set(true);
+        Ok(())
+    }
+}
+
+/// Byte-wide SPI device sending each byte as a 9-bit word with D/CX in bit 8
+pub struct NineBitSpi<'a, S> {
+    spi: S,
+    dc: &'a Cell<bool>,
+}
+
+impl<S> NineBitSpi<'_, S> {
+    /// Give back the underlying 9-bit SPI device
+    pub fn release(self) -> S {
+        self.spi
+    }
+
+    /// Encode up to `CHUNK_WORDS` bytes with the current D/CX level
+    fn encode(&self, bytes: &[u8], words: &mut [u16; CHUNK_WORDS]) -> usize {
+        let dc = (self.dc.get() as u16) << 8;
+        for (word, &byte) in words.iter_mut().zip(bytes) {
+            *word = dc | byte as u16;
+        }
+        bytes.len()
+    }
+}
+
+/// Reject a transaction with reads before any of its writes reach the bus
+fn check_write_only<E>(operations: &[Operation<'_, u8>]) -> Result<(), NineBitError<E>> {
+    let write_only = operations
+        .iter()
+        .all(|operation| matches!(operation, Operation::Write(_) | Operation::DelayNs(_)));
+    if write_only {
+        Ok(())
+    } else {
+        Err(NineBitError::ReadUnsupported)
+    }
+}
+
+impl<S: ErrorType> ErrorType for NineBitSpi<'_, S> {
+    type Error = NineBitError<S::Error>;
+}
+
+#[cfg(feature = "async")]
+impl<S> embedded_hal_async::spi::SpiDevice for NineBitSpi<'_, S>
+where
+    S: embedded_hal_async::spi::SpiDevice<u16>,
+{
+    async fn transaction(
+        &mut self,
+        operations: &mut [Operation<'_, u8>],
+    ) -> Result<(), Self::Error> {
+        check_write_only(operations)?;
+        let mut words = [0; CHUNK_WORDS];
+        for operation in operations {
+            match operation {
+                Operation::Write(bytes) => {
+                    for chunk in bytes.chunks(CHUNK_WORDS) {
+                        let len = self.encode(chunk, &mut words);
+                        self.spi
+                            .write(&words[..len])
+                            .await
+                            .map_err(NineBitError::Spi)?;
+                    }
+                }
+                Operation::DelayNs(ns) => self
+                    .spi
+                    .transaction(&mut [Operation::DelayNs(*ns)])
+                    .await
+                    .map_err(NineBitError::Spi)?,
+                _ => unreachable!("checked by check_write_only"),
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(not(feature = "async"))]
+impl<S> spi::SpiDevice for NineBitSpi<'_, S>
+where
+    S: spi::SpiDevice<u16>,
+{
+    fn transaction(&mut self, operations: &mut [Operation<'_, u8>]) -> Result<(), Self::Error> {
+        check_write_only(operations)?;
+        let mut words = [0; CHUNK_WORDS];
+        for operation in operations {
+            match operation {
+                Operation::Write(bytes) => {
+                    for chunk in bytes.chunks(CHUNK_WORDS) {
+                        let len = self.encode(chunk, &mut words);
+                        self.spi.write(&words[..len]).map_err(NineBitError::Spi)?;
+                    }
+                }
+                Operation::DelayNs(ns) => self
+                    .spi
+                    .transaction(&mut [Operation::DelayNs(*ns)])
+                    .map_err(NineBitError::Spi)?,
+                _ => unreachable!("checked by check_write_only"),
+            }
+        }
+        Ok(())
+    }
+}
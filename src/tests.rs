@@ -0,0 +1,1128 @@
+//! No-hardware tests: the driver runs against mock SPI, pins and timer
+
+extern crate std;
+
+use core::cell::RefCell;
+use core::convert::Infallible;
+use std::rc::Rc;
+use std::vec;
+use std::vec::Vec;
+
+use embedded_hal::digital::{ErrorType as PinErrorType, OutputPin};
+use embedded_hal::spi::{ErrorType, Operation};
+
+use super::*;
+
+/// SPI device that records every written chunk into a shared log
+#[derive(Clone, Default)]
+struct MockSpi {
+    log: Rc<RefCell<Vec<Vec<u8>>>>,
+}
+
+impl MockSpi {
+    fn record(&self, operations: &mut [Operation<'_, u8>]) {
+        for operation in operations {
+            match operation {
+                Operation::Write(data) => self.log.borrow_mut().push(data.to_vec()),
+                Operation::Read(data) => data.fill(0),
+                _ => {}
+            }
+        }
+    }
+
+    /// Every chunk written so far
+    fn writes(&self) -> Vec<Vec<u8>> {
+        RefCell::borrow(&self.log).clone()
+    }
+
+    /// Parameters of every `cmd` sent so far, assuming each command byte is its own write
+    fn params_of(&self, cmd: u8) -> Vec<Vec<u8>> {
+        self.writes()
+            .windows(2)
+            .filter(|pair| pair[0] == [cmd])
+            .map(|pair| pair[1].clone())
+            .collect()
+    }
+}
+
+impl ErrorType for MockSpi {
+    type Error = Infallible;
+}
+
+#[cfg(feature = "async")]
+impl embedded_hal_async::spi::SpiDevice for MockSpi {
+    async fn transaction(
+        &mut self,
+        operations: &mut [Operation<'_, u8>],
+    ) -> Result<(), Infallible> {
+        self.record(operations);
+        Ok(())
+    }
+}
+
+#[cfg(not(feature = "async"))]
+impl embedded_hal::spi::SpiDevice for MockSpi {
+    fn transaction(&mut self, operations: &mut [Operation<'_, u8>]) -> Result<(), Infallible> {
+        self.record(operations);
+        Ok(())
+    }
+}
+
+/// 9-bit SPI device that records every written word
+#[derive(Clone, Default)]
+struct MockSpi9 {
+    log: Rc<RefCell<Vec<u16>>>,
+}
+
+impl ErrorType for MockSpi9 {
+    type Error = Infallible;
+}
+
+#[cfg(feature = "async")]
+impl embedded_hal_async::spi::SpiDevice<u16> for MockSpi9 {
+    async fn transaction(
+        &mut self,
+        operations: &mut [Operation<'_, u16>],
+    ) -> Result<(), Infallible> {
+        for operation in operations {
+            if let Operation::Write(words) = operation {
+                self.log.borrow_mut().extend_from_slice(words);
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(not(feature = "async"))]
+impl embedded_hal::spi::SpiDevice<u16> for MockSpi9 {
+    fn transaction(&mut self, operations: &mut [Operation<'_, u16>]) -> Result<(), Infallible> {
+        for operation in operations {
+            if let Operation::Write(words) = operation {
+                self.log.borrow_mut().extend_from_slice(words);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Output pin that ignores every level change
+struct MockPin;
+
+impl PinErrorType for MockPin {
+    type Error = Infallible;
+}
+
+impl OutputPin for MockPin {
+    fn set_low(&mut self) -> Result<(), Infallible> {
+        Ok(())
+    }
+
+    fn set_high(&mut self) -> Result<(), Infallible> {
+        Ok(())
+    }
+}
+
+/// Output pin with a real (never returned) error type, as on HALs whose GPIOs can fail
+struct FalliblePin;
+
+impl PinErrorType for FalliblePin {
+    type Error = embedded_hal::digital::ErrorKind;
+}
+
+impl OutputPin for FalliblePin {
+    fn set_low(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn set_high(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+/// Timer that returns immediately
+struct MockTimer;
+
+#[cfg(feature = "async")]
+impl Timer for MockTimer {
+    async fn delay_ms(_milliseconds: u64) {}
+}
+
+#[cfg(not(feature = "async"))]
+impl Timer for MockTimer {
+    fn delay_ms(_milliseconds: u64) {}
+}
+
+type MockDisplay<'b> = GC9307C<'b, MockSpi, MockPin, MockPin, MockTimer>;
+
+/// Native portrait panel: 172×320 physical
+fn portrait_on(spi: MockSpi, buffer: &mut [u8]) -> MockDisplay<'_> {
+    let config = Config::for_panel(Panel::Hsd147, Orientation::Portrait);
+    let mut display = GC9307C::new(config, spi, MockPin, MockPin, buffer);
+    display.assume_initialized();
+    display
+}
+
+#[test]
+fn nine_bit_spi_carries_dc_in_bit_8() {
+    let spi = MockSpi9::default();
+    let dc = core::cell::Cell::new(false);
+    let (nine_bit, dc_pin) = nine_bit::split(spi.clone(), &dc);
+    let config = Config::for_panel(Panel::Hsd147, Orientation::Portrait);
+    let mut buffer = [0; 64];
+    let mut display: GC9307C<'_, _, _, MockPin, MockTimer> =
+        GC9307C::new(config, nine_bit, dc_pin, MockPin, &mut buffer);
+    display.assume_initialized();
+
+    block_on(display.fill_rect(0, 0, 1, 1, Rgb565::RED)).unwrap();
+    let expected = [
+        0x02A,
+        0x100,
+        0x100 | 34,
+        0x100,
+        0x100 | 34, // column address set
+        0x02B,
+        0x100,
+        0x100,
+        0x100,
+        0x100, // page address set
+        0x02C,
+        0x1F8,
+        0x100, // memory write, one red pixel
+    ];
+    assert_eq!(*RefCell::borrow(&spi.log), expected);
+
+    // A read is rejected before its command byte goes out
+    spi.log.borrow_mut().clear();
+    assert!(matches!(
+        block_on(display.read_id()),
+        Err(Error::Comm(nine_bit::NineBitError::ReadUnsupported))
+    ));
+    assert!(RefCell::borrow(&spi.log).is_empty());
+}
+
+#[test]
+fn color_bytes_are_big_endian_rgb565() {
+    for (color, bytes) in [
+        (Rgb565::BLACK, [0x00, 0x00]),
+        (Rgb565::WHITE, [0xFF, 0xFF]),
+        (Rgb565::RED, [0xF8, 0x00]),
+        (Rgb565::GREEN, [0x07, 0xE0]),
+        (Rgb565::BLUE, [0x00, 0x1F]),
+        (Rgb565::new(0x10, 0x20, 0x08), [0x84, 0x08]),
+    ] {
+        assert_eq!(color_bytes(color), bytes, "{color:?}");
+        assert_eq!(
+            raw_bytes(RawU16::from(color).into_inner()),
+            bytes,
+            "{color:?}"
+        );
+    }
+}
+
+#[test]
+fn fill_screen_reports_progress_per_batch() {
+    let spi = MockSpi::default();
+    let mut buffer = [0; 64];
+    let mut display = portrait_on(spi.clone(), &mut buffer);
+
+    let mut reports = Vec::new();
+    block_on(
+        display.fill_screen_with_progress(Rgb565::BLUE, |done, total| reports.push((done, total))),
+    )
+    .unwrap();
+
+    // 32-pixel buffer: one report per batch, ending at the full 172x320 screen
+    assert_eq!(reports.len(), 172 * 320 / 32);
+    assert_eq!(reports[0], (32, 172 * 320));
+    assert_eq!(reports.last(), Some(&(172 * 320, 172 * 320)));
+    assert_eq!(spi.writes().len(), 5 + reports.len());
+}
+
+#[test]
+fn dimensions_follow_the_orientation() {
+    let mut buffer = [0; 64];
+    let mut display = portrait_on(MockSpi::default(), &mut buffer);
+    assert_eq!(display.dimensions(), (172, 320));
+    assert_eq!(
+        display.bounding_box(),
+        Rectangle::new(Point::zero(), Size::new(172, 320))
+    );
+
+    block_on(display.set_orientation(Orientation::Landscape)).unwrap();
+    assert_eq!(display.dimensions(), (320, 172));
+}
+
+#[test]
+fn enable_scroll_derives_the_scroll_height() {
+    let spi = MockSpi::default();
+    let mut buffer = [0; 64];
+    let mut display = portrait_on(spi.clone(), &mut buffer);
+
+    block_on(display.enable_scroll(20, 40)).unwrap();
+    assert_eq!(spi.params_of(0x33), [[0, 20, 0x01, 0x04, 0, 40]]);
+
+    spi.log.borrow_mut().clear();
+    assert!(matches!(
+        block_on(display.enable_scroll(300, 21)),
+        Err(Error::InvalidArgument)
+    ));
+    assert!(matches!(
+        block_on(display.enable_scroll(u16::MAX, 1)),
+        Err(Error::InvalidArgument)
+    ));
+    assert!(spi.writes().is_empty());
+}
+
+#[test]
+fn clear_resets_the_scroll_pointer() {
+    let spi = MockSpi::default();
+    let mut buffer = [0; 64];
+    let mut display = portrait_on(spi.clone(), &mut buffer);
+
+    // No scroll area defined: back to line 0
+    block_on(display.scroll_by(16)).unwrap();
+    block_on(display.clear(Rgb565::BLACK)).unwrap();
+    assert_eq!(spi.params_of(0x37).last(), Some(&vec![0, 0]));
+
+    // With fixed bands: back to the first scrolling line; the area stays defined
+    block_on(display.enable_scroll(20, 40)).unwrap();
+    block_on(display.scroll_by(5)).unwrap();
+    spi.log.borrow_mut().clear();
+    block_on(display.clear(Rgb565::BLACK)).unwrap();
+    assert_eq!(spi.params_of(0x37), [[0, 20]]);
+    assert!(spi.params_of(0x33).is_empty());
+
+    // Already at the top: no extra command
+    spi.log.borrow_mut().clear();
+    block_on(display.clear(Rgb565::BLACK)).unwrap();
+    assert!(spi.params_of(0x37).is_empty());
+}
+
+#[test]
+fn raw_fills_send_the_value_unchanged() {
+    let spi = MockSpi::default();
+    let mut buffer = [0; 64];
+    let mut display = portrait_on(spi.clone(), &mut buffer);
+
+    block_on(display.fill_rect_raw(0, 0, 1, 1, 0xF81F)).unwrap();
+    assert_eq!(spi.writes()[5], [0xF8, 0x1F]);
+}
+
+#[test]
+fn fill_color_and_fill_screen_send_the_same_bytes() {
+    let color = Rgb565::new(0b10101, 0b110011, 0b01010);
+    let streams = [false, true].map(|use_fill_color| {
+        let spi = MockSpi::default();
+        let mut buffer = [0; 64];
+        let mut display = portrait_on(spi.clone(), &mut buffer);
+        if use_fill_color {
+            block_on(display.fill_color(color)).unwrap();
+        } else {
+            block_on(display.fill_screen(color)).unwrap();
+        }
+        spi.writes()
+    });
+
+    assert_eq!(streams[0], streams[1]);
+    assert_eq!(streams[0][5][..2], color_bytes(color));
+}
+
+#[test]
+fn fills_with_a_buffer_shorter_than_a_row() {
+    let spi = MockSpi::default();
+    let mut buffer = [0; 16]; // 8 pixels, far less than a 172-pixel row
+    let mut display = portrait_on(spi.clone(), &mut buffer);
+
+    for (width, height) in [(20, 3), (172, 320)] {
+        spi.log.borrow_mut().clear();
+        block_on(display.fill_rect(0, 0, width, height, Rgb565::RED)).unwrap();
+
+        // One window, then nothing but pixel chunks that add up to the whole area
+        let writes = spi.writes();
+        assert_eq!(spi.params_of(0x2A).len(), 1);
+        assert_eq!(spi.params_of(0x2B).len(), 1);
+        assert_eq!(writes[4], [0x2C]);
+        let pixels = &writes[5..];
+        assert!(pixels.iter().all(|chunk| chunk.len() <= 16));
+        assert!(
+            pixels
+                .concat()
+                .chunks(2)
+                .all(|p| p == color_bytes(Rgb565::RED))
+        );
+        assert_eq!(
+            pixels.iter().map(Vec::len).sum::<usize>(),
+            width as usize * height as usize * 2
+        );
+    }
+}
+
+#[test]
+fn pixel_data_uses_color_bytes() {
+    let spi = MockSpi::default();
+    let mut buffer = [0; 64];
+    let mut display = portrait_on(spi.clone(), &mut buffer);
+
+    block_on(display.set_pixel(1, 2, Rgb565::RED)).unwrap();
+    assert_eq!(spi.params_of(0x2A), [[0x00, 35, 0x00, 171 + 34]]); // x + dx (34) to row end
+    assert_eq!(spi.writes().last().unwrap(), &[0xF8, 0x00]);
+
+    block_on(display.begin_pixels(0, 0, 2, 1)).unwrap();
+    block_on(display.write_pixels(&[0x07E0, 0x001F])).unwrap();
+    assert_eq!(spi.writes().last().unwrap(), &[0x07, 0xE0, 0x00, 0x1F]);
+}
+
+#[test]
+fn adjacent_set_pixel_continues_the_row() {
+    let spi = MockSpi::default();
+    let mut buffer = [0; 64];
+    let mut display = portrait_on(spi.clone(), &mut buffer);
+
+    for x in 10..20 {
+        block_on(display.set_pixel(x, 5, Rgb565::GREEN)).unwrap();
+    }
+    // One window from x = 10 to the end of the row, then 2 bytes per pixel
+    assert_eq!(spi.params_of(0x2A), [[0x00, 10 + 34, 0x00, 171 + 34]]);
+    assert_eq!(spi.writes().len(), 5 + 10);
+
+    // Any other command ends the run
+    block_on(display.set_invert(true)).unwrap();
+    block_on(display.set_pixel(20, 5, Rgb565::GREEN)).unwrap();
+    assert_eq!(spi.params_of(0x2A).len(), 2);
+
+    // A jump re-addresses; the run ends at the last column
+    block_on(display.set_pixel(170, 6, Rgb565::GREEN)).unwrap();
+    block_on(display.set_pixel(171, 6, Rgb565::GREEN)).unwrap();
+    assert_eq!(spi.params_of(0x2A).len(), 3);
+    assert_eq!(display.pixel_cursor, None);
+
+    // At 90° a logical row runs down one GRAM column, so the run continues there too
+    #[cfg(feature = "software-rotation")]
+    {
+        display.set_rotation(Rotation::Deg90);
+        spi.log.borrow_mut().clear();
+        for x in 10..20 {
+            block_on(display.set_pixel(x, 7, Rgb565::GREEN)).unwrap();
+        }
+        assert_eq!(spi.params_of(0x2A), [[0x00, 164 + 34, 0x00, 164 + 34]]);
+        assert_eq!(spi.params_of(0x2B), [[0x00, 10, 0x01, 0x3F]]);
+        assert_eq!(spi.writes().len(), 5 + 10);
+
+        // At 180° the row runs backwards in GRAM: one single-pixel window per pixel
+        display.set_rotation(Rotation::Deg180);
+        spi.log.borrow_mut().clear();
+        block_on(display.set_pixel(10, 7, Rgb565::GREEN)).unwrap();
+        block_on(display.set_pixel(11, 7, Rgb565::GREEN)).unwrap();
+        assert_eq!(
+            spi.params_of(0x2A),
+            [
+                [0x00, 161 + 34, 0x00, 161 + 34],
+                [0x00, 160 + 34, 0x00, 160 + 34]
+            ]
+        );
+    }
+}
+
+#[test]
+fn addressing_changes_end_the_set_pixel_run() {
+    let spi = MockSpi::default();
+    let mut buffer = [0; 64];
+    let mut display = portrait_on(spi.clone(), &mut buffer);
+
+    // The next pixel would continue the run, but the offset moved under it
+    block_on(display.set_pixel(10, 5, Rgb565::GREEN)).unwrap();
+    display.set_offset(40, 0);
+    block_on(display.set_pixel(11, 5, Rgb565::GREEN)).unwrap();
+    assert_eq!(
+        spi.params_of(0x2A),
+        [
+            [0x00, 10 + 34, 0x00, 171 + 34],
+            [0x00, 11 + 40, 0x00, 171 + 40]
+        ]
+    );
+    assert_eq!(spi.params_of(0x2B).len(), 2);
+    assert_eq!(spi.writes().iter().filter(|w| **w == [0x2C]).count(), 2);
+
+    // Same for a software rotation
+    #[cfg(feature = "software-rotation")]
+    {
+        spi.log.borrow_mut().clear();
+        display.set_rotation(Rotation::Deg90);
+        block_on(display.set_pixel(12, 5, Rgb565::GREEN)).unwrap();
+        assert_eq!(spi.params_of(0x2A).len(), 1);
+        assert_eq!(spi.params_of(0x2B).len(), 1);
+        assert_eq!(spi.writes().iter().filter(|w| **w == [0x2C]).count(), 1);
+    }
+}
+
+#[test]
+fn set_color_order_only_flips_the_bgr_bit() {
+    let spi = MockSpi::default();
+    let mut buffer = [0; 64];
+    let mut display = portrait_on(spi.clone(), &mut buffer);
+
+    assert_eq!(display.madctl(), 0x48); // Portrait, BGR by default
+    block_on(display.set_madctl(0xC8)).unwrap(); // MY | MX | BGR
+    block_on(display.set_color_order(true)).unwrap();
+    assert_eq!(display.madctl(), 0xC0);
+    block_on(display.set_color_order(false)).unwrap();
+    block_on(display.set_orientation(Orientation::Landscape)).unwrap();
+    block_on(display.set_color_order(true)).unwrap();
+
+    assert_eq!(
+        spi.params_of(0x36),
+        [[0xC8], [0xC0], [0xC8], [0x28], [0x20]]
+    );
+    assert!(display.config.rgb);
+    assert_eq!(display.madctl(), 0x20);
+}
+
+#[test]
+fn panel_offset_follows_the_orientation_axes() {
+    let spi = MockSpi::default();
+    let mut buffer = [0; 64];
+    let mut display = portrait_on(spi.clone(), &mut buffer);
+
+    // (orientation, first/last column, first/last row) of a full-screen window
+    let cases = [
+        (Orientation::Portrait, (34, 171 + 34), (0, 319)),
+        (Orientation::Landscape, (0, 319), (34, 171 + 34)),
+        (Orientation::PortraitSwapped, (34, 171 + 34), (0, 319)),
+        (Orientation::LandscapeSwapped, (0, 319), (34, 171 + 34)),
+    ];
+    for (orientation, (x0, x1), (y0, y1)) in cases {
+        block_on(display.set_orientation(orientation)).unwrap();
+        spi.log.borrow_mut().clear();
+
+        let (width, height) = (display.config.width, display.config.height);
+        block_on(display.fill_rect(0, 0, width, height, Rgb565::RED)).unwrap();
+        let [x0, x1, y0, y1] = [x0, x1, y0, y1].map(|v: u16| v.to_be_bytes());
+        assert_eq!(
+            spi.params_of(0x2A),
+            [[x0, x1].concat()],
+            "MADCTL {:#04x}",
+            orientation as u8
+        );
+        assert_eq!(
+            spi.params_of(0x2B),
+            [[y0, y1].concat()],
+            "MADCTL {:#04x}",
+            orientation as u8
+        );
+    }
+}
+
+#[test]
+fn set_orientation_moves_the_bounds() {
+    let spi = MockSpi::default();
+    let mut buffer = [0; 64];
+    let mut display = portrait_on(spi.clone(), &mut buffer);
+
+    block_on(display.set_orientation(Orientation::Landscape)).unwrap();
+    assert_eq!((display.config.width, display.config.height), (320, 172));
+    spi.log.borrow_mut().clear();
+
+    // Last column and row of landscape are drawn (offset now on the rows)...
+    block_on(display.fill_rect(319, 171, 1, 1, Rgb565::RED)).unwrap();
+    assert_eq!(spi.params_of(0x2A), [[0x01, 0x3F, 0x01, 0x3F]]);
+    assert_eq!(spi.params_of(0x2B), [[0x00, 171 + 34, 0x00, 171 + 34]]);
+
+    // ...one past either edge is dropped
+    spi.log.borrow_mut().clear();
+    block_on(display.fill_rect(320, 0, 1, 1, Rgb565::RED)).unwrap();
+    block_on(display.fill_rect(0, 172, 1, 1, Rgb565::RED)).unwrap();
+    assert!(spi.writes().is_empty());
+}
+
+#[test]
+fn reapply_modes_resends_cached_state() {
+    let spi = MockSpi::default();
+    let mut buffer = [0; 64];
+    let mut display = portrait_on(spi.clone(), &mut buffer);
+
+    block_on(display.set_madctl(0xC8)).unwrap();
+    block_on(display.set_tearing_effect(TearingEffect::Off)).unwrap();
+    spi.log.borrow_mut().clear();
+
+    block_on(display.reapply_modes()).unwrap();
+    let expected: [&[u8]; 6] = [&[0x36], &[0xC8], &[0x3A], &[0x05], &[0x20], &[0x34]];
+    assert_eq!(spi.writes(), expected);
+}
+
+#[test]
+fn draw_bitmap_scaled_replicates_pixels() {
+    let spi = MockSpi::default();
+    let mut buffer = [0; 64];
+    let mut display = portrait_on(spi.clone(), &mut buffer);
+
+    // 2×2 checker, one byte per row, at 2x: a 4×4 window
+    let data = [0b1000_0000, 0b0100_0000];
+    block_on(display.draw_bitmap_scaled(0, 0, 2, 2, &data, Rgb565::WHITE, Rgb565::BLACK, 2))
+        .unwrap();
+
+    assert_eq!(spi.params_of(0x2A), [[0x00, 34, 0x00, 3 + 34]]);
+    assert_eq!(spi.params_of(0x2B), [[0x00, 0, 0x00, 3]]);
+    let pixels: Vec<u8> = spi.writes()[5..].concat();
+    let (w, b) = ([0xFF, 0xFF], [0x00, 0x00]);
+    let row_a = [w, w, b, b].concat();
+    let row_b = [b, b, w, w].concat();
+    assert_eq!(pixels, [&row_a[..], &row_a, &row_b, &row_b].concat());
+
+    assert!(matches!(
+        block_on(display.draw_bitmap_scaled(0, 0, 2, 2, &data, Rgb565::WHITE, Rgb565::BLACK, 0)),
+        Err(Error::InvalidArgument)
+    ));
+}
+
+#[test]
+fn init_sends_the_documented_sequence() {
+    let spi = MockSpi::default();
+    let mut buffer = [0; 64];
+    let config = Config::for_panel(Panel::Hsd147, Orientation::Portrait);
+    let mut display: MockDisplay<'_> =
+        GC9307C::new(config, spi.clone(), MockPin, MockPin, &mut buffer);
+    block_on(display.init()).unwrap();
+
+    // RST is toggled, so no software reset; the sequence is followed by MADCTL and INVOFF
+    let mut expected = Vec::new();
+    for command in DEFAULT_INIT_SEQUENCE {
+        expected.push(vec![command.cmd]);
+        if !command.params.is_empty() {
+            expected.push(command.params.to_vec());
+        }
+    }
+    expected.extend([vec![0x36], vec![0x48], vec![0x20]]);
+    assert_eq!(spi.writes(), expected);
+}
+
+#[test]
+fn fill_rect_clips_to_the_screen() {
+    let spi = MockSpi::default();
+    let mut buffer = [0; 64];
+    let mut display = portrait_on(spi.clone(), &mut buffer);
+
+    block_on(display.fill_rect(170, 318, 10, 10, Rgb565::RED)).unwrap();
+    assert_eq!(spi.params_of(0x2A), [[0x00, 170 + 34, 0x00, 171 + 34]]);
+    assert_eq!(spi.params_of(0x2B), [[0x01, 0x3E, 0x01, 0x3F]]);
+    assert_eq!(spi.writes()[5..].concat().len(), 2 * 2 * 2);
+
+    spi.log.borrow_mut().clear();
+    block_on(display.fill_rect(172, 0, 4, 4, Rgb565::RED)).unwrap();
+    block_on(display.fill_rect(0, 320, 4, 4, Rgb565::RED)).unwrap();
+    block_on(display.fill_rect(0, 0, 0, 4, Rgb565::RED)).unwrap();
+    assert!(spi.writes().is_empty());
+}
+
+#[test]
+fn no_reset_pin_works_with_fallible_gpios() {
+    let spi = MockSpi::default();
+    let mut buffer = [0; 64];
+    let config = Config::for_panel(Panel::Hsd147, Orientation::Portrait);
+    let mut display: GC9307C<'_, _, _, NoPin<_>, MockTimer> =
+        GC9307C::new_without_reset(config, spi.clone(), FalliblePin, &mut buffer);
+
+    // Without a pin, reset() is a software reset (01h)
+    block_on(display.init()).unwrap();
+    assert_eq!(spi.writes()[0], [0x01]);
+}
+
+#[test]
+fn drawing_requires_init() {
+    let spi = MockSpi::default();
+    let mut buffer = [0; 64];
+    let mut display = portrait_on(spi.clone(), &mut buffer);
+
+    block_on(display.soft_reset()).unwrap();
+    assert!(!display.is_initialized());
+    spi.log.borrow_mut().clear();
+    assert!(matches!(
+        block_on(display.fill_rect(0, 0, 4, 4, Rgb565::RED)),
+        Err(Error::NotInitialized)
+    ));
+    assert!(spi.writes().is_empty());
+
+    block_on(display.init()).unwrap();
+    assert!(display.is_initialized());
+    block_on(display.fill_rect(0, 0, 4, 4, Rgb565::RED)).unwrap();
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn long_fills_yield_between_batches() {
+    use core::task::{Context, Waker};
+
+    let spi = MockSpi::default();
+    let mut buffer = [0; 64]; // 32 pixels per write
+    let mut display = portrait_on(spi.clone(), &mut buffer);
+    display.config.yield_every_batches = Some(2);
+
+    // 128 pixels: 4 writes, so 2 yields
+    let mut fill = core::pin::pin!(display.fill_rect(0, 0, 16, 8, Rgb565::RED));
+    let mut cx = Context::from_waker(Waker::noop());
+    let mut pending = 0;
+    while fill.as_mut().poll(&mut cx).is_pending() {
+        pending += 1;
+    }
+    assert_eq!(pending, 2);
+}
+
+#[test]
+fn set_address_window_rejects_inverted_coordinates() {
+    let spi = MockSpi::default();
+    let mut buffer = [0; 64];
+    let mut display = portrait_on(spi.clone(), &mut buffer);
+
+    for (sx, sy, ex, ey) in [(10, 0, 9, 0), (0, 5, 0, 4)] {
+        assert!(matches!(
+            block_on(display.set_address_window(sx, sy, ex, ey)),
+            Err(Error::InvalidArgument)
+        ));
+    }
+    assert!(spi.writes().is_empty());
+}
+
+#[test]
+fn arc_points_run_clockwise_from_three_oclock() {
+    for (deg, point) in [
+        (0, (100, 0)),
+        (45, (71, 71)),
+        (90, (0, 100)),
+        (180, (-100, 0)),
+        (270, (0, -100)),
+        (-90, (0, -100)),
+    ] {
+        assert_eq!(
+            arc_point(0, 0, 100, deg),
+            Point::new(point.0, point.1),
+            "{deg}°"
+        );
+    }
+}
+
+#[test]
+fn draw_arc_wraps_and_clips() {
+    let spi = MockSpi::default();
+    let mut buffer = [0; 64];
+    let mut display = portrait_on(spi.clone(), &mut buffer);
+
+    // Zero sweep: just the start point
+    block_on(display.draw_arc(20, 20, 10, 0, 0, 1, Rgb565::RED)).unwrap();
+    assert_eq!(spi.params_of(0x2A), [[0x00, 30 + 34, 0x00, 30 + 34]]);
+    assert_eq!(spi.params_of(0x2B), [[0x00, 20, 0x00, 20]]);
+
+    // 270° gauge ring around a corner, mostly off screen
+    block_on(display.draw_arc(0, 0, 40, 135, 45, 3, Rgb565::RED)).unwrap();
+}
+
+#[test]
+fn fill_triangle_writes_one_span_per_row() {
+    let spi = MockSpi::default();
+    let mut buffer = [0; 64];
+    let mut display = portrait_on(spi.clone(), &mut buffer);
+
+    // Right triangle with its top row above the screen: rows 0..=3 remain
+    block_on(display.fill_triangle((0, -1), (0, 3), (4, -1), Rgb565::RED)).unwrap();
+
+    let columns: Vec<_> = spi.params_of(0x2A).iter().map(|p| p[3] - 34).collect();
+    let rows: Vec<_> = spi.params_of(0x2B).iter().map(|p| p[1]).collect();
+    assert_eq!(columns, [3, 2, 1, 0]);
+    assert_eq!(rows, [0, 1, 2, 3]);
+}
+
+#[test]
+fn fill_rect_signed_clips_to_the_screen() {
+    let spi = MockSpi::default();
+    let mut buffer = [0; 64];
+    let mut display = portrait_on(spi.clone(), &mut buffer);
+
+    block_on(display.fill_rect_signed(-3, 318, 5, 10, Rgb565::RED)).unwrap();
+    assert_eq!(spi.params_of(0x2A), [[0x00, 34, 0x00, 1 + 34]]);
+    assert_eq!(spi.params_of(0x2B), [[0x01, 0x3E, 0x01, 0x3F]]);
+
+    spi.log.borrow_mut().clear();
+    block_on(display.fill_rect_signed(-5, 10, 5, 5, Rgb565::RED)).unwrap();
+    block_on(display.fill_rect_signed(172, 10, 5, 5, Rgb565::RED)).unwrap();
+    block_on(display.fill_rect_signed(i32::MAX, 0, u32::MAX, 1, Rgb565::RED)).unwrap();
+    assert!(spi.writes().is_empty());
+}
+
+/// Image source that hands out at most three bytes per read
+#[cfg(feature = "embedded-io")]
+struct Trickle<'a>(&'a [u8]);
+
+#[cfg(feature = "embedded-io")]
+impl embedded_io::ErrorType for Trickle<'_> {
+    type Error = embedded_io::ErrorKind;
+}
+
+#[cfg(feature = "embedded-io")]
+impl Trickle<'_> {
+    fn take(&mut self, buf: &mut [u8]) -> usize {
+        let len = buf.len().min(3).min(self.0.len());
+        buf[..len].copy_from_slice(&self.0[..len]);
+        self.0 = &self.0[len..];
+        len
+    }
+}
+
+#[cfg(all(feature = "embedded-io", feature = "async"))]
+impl embedded_io_async::Read for Trickle<'_> {
+    async fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+        Ok(self.take(buf))
+    }
+}
+
+#[cfg(all(feature = "embedded-io", not(feature = "async")))]
+impl embedded_io::Read for Trickle<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+        Ok(self.take(buf))
+    }
+}
+
+#[cfg(feature = "embedded-io")]
+#[test]
+fn write_image_from_forwards_whole_pixels() {
+    let spi = MockSpi::default();
+    let mut buffer = [0; 64];
+    let mut display = portrait_on(spi.clone(), &mut buffer);
+
+    let image = [1, 2, 3, 4, 5, 6, 7, 8];
+    block_on(display.write_image_from(0, 0, 2, 2, &mut Trickle(&image))).unwrap();
+    assert_eq!(spi.params_of(0x2A), [[0x00, 34, 0x00, 1 + 34]]);
+    let writes = spi.writes();
+    assert!(writes[5..].iter().all(|w| w.len() % 2 == 0));
+    assert_eq!(writes[5..].concat(), image);
+
+    assert!(matches!(
+        block_on(display.write_image_from(0, 0, 2, 2, &mut Trickle(&image[..6]))),
+        Err(Error::Read(embedded_io::ErrorKind::InvalidData))
+    ));
+}
+
+#[test]
+fn fill_rect_with_streams_row_major() {
+    let spi = MockSpi::default();
+    let mut buffer = [0; 64];
+    let mut display = portrait_on(spi.clone(), &mut buffer);
+
+    block_on(display.fill_rect_with(1, 2, 3, 2, |col, row| Rgb565::new(col as u8, row as u8, 0)))
+        .unwrap();
+
+    assert_eq!(spi.params_of(0x2A), [[0x00, 1 + 34, 0x00, 3 + 34]]);
+    assert_eq!(spi.params_of(0x2B), [[0x00, 2, 0x00, 3]]);
+    let expected: Vec<u8> = (0..2)
+        .flat_map(|row| (0..3).map(move |col| Rgb565::new(col, row, 0)))
+        .flat_map(color_bytes)
+        .collect();
+    assert_eq!(spi.writes()[5..].concat(), expected);
+}
+
+#[test]
+fn draw_progress_bar_redraws_only_the_delta() {
+    let spi = MockSpi::default();
+    let mut buffer = [0; 64];
+    let mut display = portrait_on(spi.clone(), &mut buffer);
+    let (fg, bg, border) = (Rgb565::GREEN, Rgb565::BLACK, Rgb565::WHITE);
+
+    // 12x3 bar: a 10x1 inside, half filled
+    block_on(display.draw_progress_bar(0, 0, 12, 3, 50, fg, bg, border)).unwrap();
+    assert_eq!(spi.params_of(0x2A).len(), 6);
+
+    spi.log.borrow_mut().clear();
+    block_on(display.draw_progress_bar(0, 0, 12, 3, 200, fg, bg, border)).unwrap();
+    assert_eq!(spi.params_of(0x2A), [[0x00, 6 + 34, 0x00, 10 + 34]]);
+    assert_eq!(spi.params_of(0x2B), [[0x00, 1, 0x00, 1]]);
+
+    spi.log.borrow_mut().clear();
+    block_on(display.draw_progress_bar(0, 0, 12, 3, 100, fg, bg, border)).unwrap();
+    assert!(spi.writes().is_empty());
+
+    assert!(matches!(
+        block_on(display.draw_progress_bar(0, 0, 2, 3, 0, fg, bg, border)),
+        Err(Error::InvalidArgument)
+    ));
+
+    // Past the u16 coordinate range it is rejected without drawing...
+    spi.log.borrow_mut().clear();
+    for (x, y, width, height) in [(10, 10, u16::MAX, 10), (10, 10, 10, u16::MAX)] {
+        assert!(matches!(
+            block_on(display.draw_progress_bar(x, y, width, height, 50, fg, bg, border)),
+            Err(Error::InvalidArgument)
+        ));
+    }
+    assert!(spi.writes().is_empty());
+
+    // ...while a bar running off the screen is clipped
+    block_on(display.draw_progress_bar(160, 316, 100, 10, 50, fg, bg, border)).unwrap();
+    let end = |p: &Vec<u8>| u16::from_be_bytes([p[2], p[3]]);
+    assert!(!spi.params_of(0x2A).is_empty());
+    assert!(spi.params_of(0x2A).iter().all(|p| end(p) <= 171 + 34));
+    assert!(spi.params_of(0x2B).iter().all(|p| end(p) <= 319));
+}
+
+#[test]
+fn blit_text_row_uses_one_window() {
+    let spi = MockSpi::default();
+    let mut buffer = [0; 64];
+    let mut display = portrait_on(spi.clone(), &mut buffer);
+
+    // Two 2x2 glyphs: a left bar and a right bar
+    let (left, right): (&[u8], &[u8]) = (&[0x80, 0x80], &[0x40, 0x40]);
+    block_on(display.blit_text_row(0, 0, &[left, right], 2, 2, Rgb565::WHITE, Rgb565::BLACK))
+        .unwrap();
+
+    assert_eq!(spi.params_of(0x2A), [[0x00, 34, 0x00, 3 + 34]]);
+    assert_eq!(spi.params_of(0x2B), [[0x00, 0, 0x00, 1]]);
+    let (w, b) = ([0xFF, 0xFF], [0x00, 0x00]);
+    let row = [w, b, b, w].concat();
+    assert_eq!(spi.writes()[5..].concat(), [&row[..], &row].concat());
+
+    assert!(matches!(
+        block_on(display.blit_text_row(0, 0, &[&[0x80]], 2, 2, Rgb565::WHITE, Rgb565::BLACK)),
+        Err(Error::InvalidArgument)
+    ));
+}
+
+struct Bars;
+
+impl font::GlyphSource for Bars {
+    fn size(&self) -> (u16, u16) {
+        (2, 1)
+    }
+
+    fn glyph(&self, c: char) -> Option<&[u8]> {
+        match c {
+            '|' => Some(&[0b1000_0000]),
+            '!' => Some(&[0b1000_0000, 0]),
+            _ => None,
+        }
+    }
+}
+
+#[test]
+fn draw_glyphs_skips_missing_glyphs() {
+    let spi = MockSpi::default();
+    let mut buffer = [0; 64];
+    let mut display = portrait_on(spi.clone(), &mut buffer);
+
+    block_on(display.draw_glyphs(&Bars, 0, 0, " |", Rgb565::WHITE, Rgb565::BLACK)).unwrap();
+
+    // ' ' has no glyph, so the only window is the second cell
+    assert_eq!(spi.params_of(0x2A), [[0x00, 2 + 34, 0x00, 3 + 34]]);
+    assert_eq!(spi.writes()[5..].concat(), [0xFF, 0xFF, 0x00, 0x00]);
+
+    assert!(matches!(
+        block_on(display.draw_glyphs(&Bars, 0, 0, "!", Rgb565::WHITE, Rgb565::BLACK)),
+        Err(Error::InvalidArgument)
+    ));
+}
+
+#[cfg(feature = "framebuffer")]
+#[test]
+fn present_sends_the_whole_framebuffer() {
+    use crate::framebuffer::FrameBuffer;
+
+    let spi = MockSpi::default();
+    let mut buffer = [0; 64];
+    let mut display = portrait_on(spi.clone(), &mut buffer);
+
+    let mut pixels = [0; 6];
+    let mut fb = FrameBuffer::new(&mut pixels, 3, 2).unwrap();
+    fb.clear(Rgb565::BLACK);
+    fb.fill_rect(1, 0, 5, 5, Rgb565::WHITE);
+    fb.set_pixel(0, 1, Rgb565::RED);
+    fb.set_pixel(3, 0, Rgb565::RED); // outside, ignored
+    fb.draw_iter([Pixel(Point::new(-1, 0), Rgb565::RED)])
+        .unwrap();
+    assert_eq!(fb.pixel(0, 1), Some(Rgb565::RED));
+
+    block_on(display.present(&mut fb)).unwrap();
+    assert_eq!(spi.params_of(0x2A), [[0x00, 34, 0x00, 2 + 34]]);
+    assert_eq!(spi.params_of(0x2B), [[0x00, 0, 0x00, 1]]);
+    let (w, b, r) = ([0xFF, 0xFF], [0x00, 0x00], color_bytes(Rgb565::RED));
+    assert_eq!(spi.writes()[5..].concat(), [b, w, w, r, w, w].concat());
+
+    assert!(FrameBuffer::new(&mut [0; 5], 3, 2).is_none());
+}
+
+#[cfg(feature = "framebuffer")]
+#[test]
+fn present_sends_only_the_dirty_region() {
+    use crate::framebuffer::FrameBuffer;
+
+    let spi = MockSpi::default();
+    let mut buffer = [0; 64];
+    let mut display = portrait_on(spi.clone(), &mut buffer);
+    let mut pixels = [0; 16];
+    let mut fb = FrameBuffer::new(&mut pixels, 4, 4).unwrap();
+    block_on(display.present(&mut fb)).unwrap();
+
+    spi.log.borrow_mut().clear();
+    block_on(display.present(&mut fb)).unwrap();
+    assert!(spi.writes().is_empty());
+
+    fb.set_pixel(1, 2, Rgb565::RED);
+    fb.fill_rect(2, 1, 1, 1, Rgb565::RED);
+    assert_eq!(fb.dirty_region(), Some((1, 1, 2, 2)));
+    block_on(display.present(&mut fb)).unwrap();
+    assert_eq!(spi.params_of(0x2A), [[0x00, 1 + 34, 0x00, 2 + 34]]);
+    assert_eq!(spi.params_of(0x2B), [[0x00, 1, 0x00, 2]]);
+    assert_eq!(fb.dirty_region(), None);
+}
+
+#[cfg(feature = "software-rotation")]
+mod rotation {
+    use super::*;
+
+    fn portrait(buffer: &mut [u8]) -> MockDisplay<'_> {
+        portrait_on(MockSpi::default(), buffer)
+    }
+
+    #[test]
+    fn logical_dimensions_follow_rotation() {
+        let mut buffer = [0; 64];
+        let mut display = portrait(&mut buffer);
+
+        for (rotation, size) in [
+            (Rotation::Deg0, (172, 320)),
+            (Rotation::Deg90, (320, 172)),
+            (Rotation::Deg180, (172, 320)),
+            (Rotation::Deg270, (320, 172)),
+        ] {
+            display.set_rotation(rotation);
+            assert_eq!(display.logical_dimensions(), size);
+        }
+    }
+
+    #[test]
+    fn transform_coordinates_corners_and_center() {
+        let mut buffer = [0; 64];
+        let mut display = portrait(&mut buffer);
+
+        // Logical (x, y) → physical (x, y) on the 172×320 panel
+        let cases = [
+            (
+                Rotation::Deg0,
+                [
+                    ((0, 0), (0, 0)),
+                    ((171, 319), (171, 319)),
+                    ((86, 160), (86, 160)),
+                ],
+            ),
+            (
+                Rotation::Deg90,
+                [
+                    ((0, 0), (171, 0)),
+                    ((319, 171), (0, 319)),
+                    ((160, 86), (85, 160)),
+                ],
+            ),
+            (
+                Rotation::Deg180,
+                [
+                    ((0, 0), (171, 319)),
+                    ((171, 319), (0, 0)),
+                    ((86, 160), (85, 159)),
+                ],
+            ),
+            (
+                Rotation::Deg270,
+                [
+                    ((0, 0), (0, 319)),
+                    ((319, 171), (171, 0)),
+                    ((160, 86), (86, 159)),
+                ],
+            ),
+        ];
+
+        for (rotation, points) in cases {
+            display.set_rotation(rotation);
+            for ((x, y), expected) in points {
+                assert_eq!(
+                    display.transform_coordinates(x, y),
+                    expected,
+                    "{rotation:?} ({x}, {y})"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn transform_coordinates_stay_on_panel() {
+        let mut buffer = [0; 64];
+        let mut display = portrait(&mut buffer);
+
+        for rotation in [
+            Rotation::Deg0,
+            Rotation::Deg90,
+            Rotation::Deg180,
+            Rotation::Deg270,
+        ] {
+            display.set_rotation(rotation);
+            let (width, height) = display.logical_dimensions();
+            for (x, y) in [
+                (0, 0),
+                (width - 1, 0),
+                (0, height - 1),
+                (width - 1, height - 1),
+            ] {
+                let (px, py) = display.transform_coordinates(x, y);
+                assert!(
+                    px < 172 && py < 320,
+                    "{rotation:?} ({x}, {y}) → ({px}, {py})"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn transform_rect_full_screen_and_inset() {
+        let mut buffer = [0; 64];
+        let mut display = portrait(&mut buffer);
+
+        // Logical (x, y, w, h) → physical (x, y, w, h)
+        let cases = [
+            (Rotation::Deg0, (10, 20, 30, 40), (10, 20, 30, 40)),
+            (Rotation::Deg90, (10, 20, 30, 40), (112, 10, 40, 30)),
+            (Rotation::Deg180, (10, 20, 30, 40), (132, 260, 30, 40)),
+            (Rotation::Deg270, (10, 20, 30, 40), (20, 280, 40, 30)),
+            (Rotation::Deg90, (0, 0, 320, 172), (0, 0, 172, 320)),
+            (Rotation::Deg270, (0, 0, 320, 172), (0, 0, 172, 320)),
+        ];
+
+        for (rotation, (x, y, w, h), expected) in cases {
+            display.set_rotation(rotation);
+            assert_eq!(display.transform_rect(x, y, w, h), expected, "{rotation:?}");
+        }
+    }
+
+    #[test]
+    fn fill_rect_sets_transposed_window_when_rotated() {
+        // Logical (10, 20) 30×40 → physical (x, y) 40×30 as in `transform_rect`, plus dx = 34
+        for (rotation, columns, rows) in [
+            (Rotation::Deg0, (44, 73), (20, 59)),
+            (Rotation::Deg90, (146, 185), (10, 39)),
+            (Rotation::Deg180, (166, 195), (260, 299)),
+            (Rotation::Deg270, (54, 93), (280, 309)),
+        ] {
+            let spi = MockSpi::default();
+            let mut buffer = [0; 64];
+            let mut display = portrait_on(spi.clone(), &mut buffer);
+            display.set_rotation(rotation);
+
+            block_on(display.fill_rect(10, 20, 30, 40, Rgb565::RED)).unwrap();
+
+            let window = |(start, end): (u16, u16)| {
+                let [start_hi, start_lo] = start.to_be_bytes();
+                let [end_hi, end_lo] = end.to_be_bytes();
+                std::vec![start_hi, start_lo, end_hi, end_lo]
+            };
+            assert_eq!(spi.params_of(0x2A), [window(columns)], "{rotation:?}");
+            assert_eq!(spi.params_of(0x2B), [window(rows)], "{rotation:?}");
+
+            // After 2Ah, 2Bh and 2Ch (command and parameter writes), every pixel follows
+            let pixel_bytes: usize = spi.writes().iter().skip(5).map(Vec::len).sum();
+            assert_eq!(pixel_bytes, 30 * 40 * 2, "{rotation:?}");
+        }
+    }
+}